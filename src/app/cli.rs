@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use clap::Parser;
 use radroots_runtime::RadrootsServiceCliArgs;
 
@@ -10,4 +12,7 @@ use radroots_runtime::RadrootsServiceCliArgs;
 pub struct Args {
     #[command(flatten)]
     pub service: RadrootsServiceCliArgs,
+    /// Config file deep-merged over --config; repeat to layer several, later files win.
+    #[arg(long = "config-overlay", value_name = "PATH")]
+    pub config_overlays: Vec<PathBuf>,
 }
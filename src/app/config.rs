@@ -2,6 +2,7 @@ use anyhow::{Context, Result, bail};
 use radroots_nostr::prelude::RadrootsNostrMetadata;
 use radroots_runtime::RadrootsNostrServiceConfig;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use super::paths::{
@@ -21,6 +22,18 @@ fn default_max_response_body_size() -> u32 {
     10 * 1024 * 1024
 }
 
+fn default_shutdown_drain_secs() -> u64 {
+    10
+}
+
+fn default_method_concurrency_policy() -> MethodConcurrencyPolicy {
+    MethodConcurrencyPolicy::Reject
+}
+
+fn default_suggest_unknown_methods() -> bool {
+    true
+}
+
 fn default_max_connections() -> u32 {
     100
 }
@@ -45,6 +58,10 @@ fn default_nip46_public_jsonrpc_enabled() -> bool {
     false
 }
 
+fn default_nip46_listener_idle_resubscribe_secs() -> u64 {
+    300
+}
+
 fn default_bridge_enabled() -> bool {
     false
 }
@@ -69,10 +86,34 @@ fn default_bridge_publish_max_backoff_millis() -> u64 {
     2_000
 }
 
+fn default_bridge_publish_reconnect_failed() -> bool {
+    true
+}
+
 fn default_bridge_job_status_retention() -> usize {
     256
 }
 
+fn default_bridge_listing_max_images() -> usize {
+    16
+}
+
+fn default_bridge_max_event_bytes() -> usize {
+    256 * 1024
+}
+
+fn default_bridge_max_tags() -> usize {
+    2_000
+}
+
+fn default_bridge_min_relays_for_publish() -> usize {
+    1
+}
+
+fn default_bridge_relay_error_history() -> usize {
+    20
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 struct RawServiceConfig {
     #[serde(default)]
@@ -86,16 +127,45 @@ struct RawServiceConfig {
 }
 
 impl RawServiceConfig {
-    fn into_service_config(self, paths: &RadrootsdRuntimePaths) -> RadrootsNostrServiceConfig {
-        RadrootsNostrServiceConfig {
+    fn into_service_config(
+        self,
+        paths: &RadrootsdRuntimePaths,
+    ) -> Result<RadrootsNostrServiceConfig> {
+        Ok(RadrootsNostrServiceConfig {
             logs_dir: self
                 .logs_dir
                 .unwrap_or_else(|| paths.logs_dir.display().to_string()),
-            relays: self.relays,
+            relays: normalize_relay_urls(self.relays).context("config.relays")?,
             nip89_identifier: self.nip89_identifier,
             nip89_extra_tags: self.nip89_extra_tags,
+        })
+    }
+}
+
+pub fn normalize_relay_url(raw: &str) -> Result<String> {
+    let url = url::Url::parse(raw.trim()).with_context(|| format!("invalid relay url {raw}"))?;
+    if !matches!(url.scheme(), "ws" | "wss") {
+        bail!("relay url {raw} must use ws:// or wss://");
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        bail!("relay url {raw} is missing a host");
+    }
+    let mut normalized = url.to_string();
+    if url.path() == "/" && url.query().is_none() && url.fragment().is_none() {
+        normalized.pop();
+    }
+    Ok(normalized)
+}
+
+fn normalize_relay_urls(relays: Vec<String>) -> Result<Vec<String>> {
+    let mut normalized = Vec::with_capacity(relays.len());
+    for relay in relays {
+        let relay = normalize_relay_url(&relay)?;
+        if !normalized.contains(&relay) {
+            normalized.push(relay);
         }
     }
+    Ok(normalized)
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -116,10 +186,36 @@ struct RawBridgeConfig {
     pub publish_initial_backoff_millis: u64,
     #[serde(default = "default_bridge_publish_max_backoff_millis")]
     pub publish_max_backoff_millis: u64,
+    #[serde(default = "default_bridge_publish_reconnect_failed")]
+    pub publish_reconnect_failed: bool,
     #[serde(default = "default_bridge_job_status_retention")]
     pub job_status_retention: usize,
     #[serde(default)]
     pub state_path: Option<PathBuf>,
+    #[serde(default)]
+    pub self_sign_kinds: Option<Vec<u32>>,
+    #[serde(default)]
+    pub trim_content: bool,
+    #[serde(default)]
+    pub reject_control_chars: bool,
+    #[serde(default = "default_bridge_listing_max_images")]
+    pub listing_max_images: usize,
+    #[serde(default)]
+    pub listing_verify_references: bool,
+    #[serde(default = "default_bridge_max_event_bytes")]
+    pub max_event_bytes: usize,
+    #[serde(default)]
+    pub trace_relay_messages: bool,
+    #[serde(default = "default_bridge_max_tags")]
+    pub max_tags: usize,
+    #[serde(default)]
+    pub fallback_relays: Vec<String>,
+    #[serde(default = "default_bridge_min_relays_for_publish")]
+    pub min_relays_for_publish: usize,
+    #[serde(default)]
+    pub kind_aliases: HashMap<String, u32>,
+    #[serde(default = "default_bridge_relay_error_history")]
+    pub relay_error_history: usize,
 }
 
 impl Default for RawBridgeConfig {
@@ -133,8 +229,21 @@ impl Default for RawBridgeConfig {
             publish_max_attempts: default_bridge_publish_max_attempts(),
             publish_initial_backoff_millis: default_bridge_publish_initial_backoff_millis(),
             publish_max_backoff_millis: default_bridge_publish_max_backoff_millis(),
+            publish_reconnect_failed: default_bridge_publish_reconnect_failed(),
             job_status_retention: default_bridge_job_status_retention(),
             state_path: None,
+            self_sign_kinds: None,
+            trim_content: false,
+            reject_control_chars: false,
+            listing_max_images: default_bridge_listing_max_images(),
+            listing_verify_references: false,
+            max_event_bytes: default_bridge_max_event_bytes(),
+            trace_relay_messages: false,
+            max_tags: default_bridge_max_tags(),
+            fallback_relays: Vec::new(),
+            min_relays_for_publish: default_bridge_min_relays_for_publish(),
+            kind_aliases: HashMap::new(),
+            relay_error_history: default_bridge_relay_error_history(),
         }
     }
 }
@@ -150,10 +259,23 @@ impl RawBridgeConfig {
             publish_max_attempts: self.publish_max_attempts,
             publish_initial_backoff_millis: self.publish_initial_backoff_millis,
             publish_max_backoff_millis: self.publish_max_backoff_millis,
+            publish_reconnect_failed: self.publish_reconnect_failed,
             job_status_retention: self.job_status_retention,
             state_path: self
                 .state_path
                 .unwrap_or_else(|| paths.bridge_state_path.clone()),
+            self_sign_kinds: self.self_sign_kinds,
+            trim_content: self.trim_content,
+            reject_control_chars: self.reject_control_chars,
+            listing_max_images: self.listing_max_images,
+            listing_verify_references: self.listing_verify_references,
+            max_event_bytes: self.max_event_bytes,
+            trace_relay_messages: self.trace_relay_messages,
+            max_tags: self.max_tags,
+            fallback_relays: self.fallback_relays,
+            min_relays_for_publish: self.min_relays_for_publish,
+            kind_aliases: HashMap::new(),
+            relay_error_history: self.relay_error_history,
         }
     }
 }
@@ -167,6 +289,14 @@ struct RawConfiguration {
     #[serde(default)]
     pub rpc_addr: Option<String>,
     #[serde(default)]
+    pub identity_source: IdentitySource,
+    #[serde(default)]
+    pub metadata_pubkey_mismatch: MetadataPubkeyMismatchPolicy,
+    #[serde(default)]
+    pub merge_startup_metadata: bool,
+    #[serde(default)]
+    pub max_relays: Option<usize>,
+    #[serde(default)]
     pub nip46: Nip46Config,
     #[serde(default)]
     pub bridge: RawBridgeConfig,
@@ -179,39 +309,105 @@ struct RawSettings {
 }
 
 impl RawSettings {
-    fn into_settings(self, paths: &RadrootsdRuntimePaths) -> Settings {
-        Settings {
+    fn into_settings(self, paths: &RadrootsdRuntimePaths) -> Result<Settings> {
+        let mut nip46 = self.config.nip46;
+        nip46.relays = normalize_relay_urls(nip46.relays).context("config.nip46.relays")?;
+        nip46.listener_relays =
+            normalize_relay_urls(nip46.listener_relays).context("config.nip46.listener_relays")?;
+        let mut raw_bridge = self.config.bridge;
+        let kind_aliases = parse_kind_aliases(std::mem::take(&mut raw_bridge.kind_aliases))
+            .context("config.bridge.kind_aliases")?;
+        let mut bridge = raw_bridge.into_bridge_config(paths);
+        bridge.kind_aliases = kind_aliases;
+        bridge.fallback_relays = normalize_relay_urls(bridge.fallback_relays)
+            .context("config.bridge.fallback_relays")?;
+        Ok(Settings {
             metadata: self.metadata,
             config: Configuration {
-                service: self.config.service.into_service_config(paths),
+                service: self.config.service.into_service_config(paths)?,
                 rpc: self.config.rpc,
                 rpc_addr: self.config.rpc_addr,
-                nip46: self.config.nip46,
-                bridge: self.config.bridge.into_bridge_config(paths),
+                identity_source: self.config.identity_source,
+                metadata_pubkey_mismatch: self.config.metadata_pubkey_mismatch,
+                merge_startup_metadata: self.config.merge_startup_metadata,
+                max_relays: self.config.max_relays,
+                nip46,
+                bridge,
             },
+        })
+    }
+}
+
+fn parse_kind_aliases(raw: HashMap<String, u32>) -> Result<HashMap<u32, u32>> {
+    raw.into_iter()
+        .map(|(old, current)| {
+            let old_kind = old
+                .trim()
+                .parse::<u32>()
+                .with_context(|| format!("invalid kind {old:?}"))?;
+            if old_kind == current {
+                bail!("kind {old_kind} cannot alias itself");
+            }
+            Ok((old_kind, current))
+        })
+        .collect()
+}
+
+fn load_raw_settings(path: &Path, overlays: &[PathBuf]) -> Result<RawSettings> {
+    if overlays.is_empty() {
+        return radroots_runtime::load_required_file(path)
+            .with_context(|| format!("load configuration from {}", path.display()));
+    }
+    let mut merged = serde_json::Value::Object(Default::default());
+    for path in std::iter::once(path).chain(overlays.iter().map(PathBuf::as_path)) {
+        let layer: serde_json::Value = radroots_runtime::load_required_file(path)
+            .with_context(|| format!("load configuration from {}", path.display()))?;
+        merge_config_layer(&mut merged, layer);
+    }
+    serde_json::from_value(merged).context("parse layered configuration")
+}
+
+// Tables merge key by key; arrays and scalars from later layers replace earlier ones.
+fn merge_config_layer(base: &mut serde_json::Value, layer: serde_json::Value) {
+    match (base, layer) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_config_layer(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
         }
+        (base, layer) => *base = layer,
     }
 }
 
 fn load_settings_from_path_with_resolver(
     path: &Path,
+    overlays: &[PathBuf],
     resolver: &radroots_runtime_paths::RadrootsPathResolver,
     profile: radroots_runtime_paths::RadrootsPathProfile,
     repo_local_root: Option<&Path>,
 ) -> Result<Settings> {
-    let raw: RawSettings = radroots_runtime::load_required_file(path)
-        .with_context(|| format!("load configuration from {}", path.display()))?;
+    let raw = load_raw_settings(path, overlays)?;
     let paths = resolve_runtime_paths_with_resolver(resolver, profile, repo_local_root)?;
-    let settings = raw.into_settings(&paths);
+    let settings = raw.into_settings(&paths)?;
     settings.validate()?;
     Ok(settings)
 }
 
 pub fn load_settings_from_path(path: impl AsRef<Path>) -> Result<Settings> {
+    load_settings_from_paths(path, &[])
+}
+
+pub fn load_settings_from_paths(path: impl AsRef<Path>, overlays: &[PathBuf]) -> Result<Settings> {
     let path = path.as_ref();
     let (profile, repo_local_root) = process_path_selection()?;
     load_settings_from_path_with_resolver(
         path,
+        overlays,
         &radroots_runtime_paths::RadrootsPathResolver::current(),
         profile,
         repo_local_root.as_deref(),
@@ -222,21 +418,42 @@ pub fn load_settings_from_path(path: impl AsRef<Path>) -> Result<Settings> {
 pub struct Nip46Config {
     #[serde(default = "default_nip46_session_ttl_secs")]
     pub session_ttl_secs: u64,
+    #[serde(default)]
+    pub session_idle_timeout_secs: Option<u64>,
     #[serde(default = "default_nip46_perms")]
     pub perms: Vec<String>,
     #[serde(default = "default_nip46_public_jsonrpc_enabled")]
     pub public_jsonrpc_enabled: bool,
     #[serde(default)]
     pub nostrconnect_url: Option<String>,
+    #[serde(default)]
+    pub relays: Vec<String>,
+    #[serde(default)]
+    pub listener_relays: Vec<String>,
+    #[serde(default)]
+    pub listener_allowed_authors: Vec<String>,
+    #[serde(default)]
+    pub listener_banned_authors: Vec<String>,
+    #[serde(default)]
+    pub listener_max_events_per_author_per_minute: Option<u32>,
+    #[serde(default = "default_nip46_listener_idle_resubscribe_secs")]
+    pub listener_idle_resubscribe_secs: u64,
 }
 
 impl Default for Nip46Config {
     fn default() -> Self {
         Self {
             session_ttl_secs: default_nip46_session_ttl_secs(),
+            session_idle_timeout_secs: None,
             perms: default_nip46_perms(),
             public_jsonrpc_enabled: default_nip46_public_jsonrpc_enabled(),
             nostrconnect_url: None,
+            relays: Vec::new(),
+            listener_relays: Vec::new(),
+            listener_allowed_authors: Vec::new(),
+            listener_banned_authors: Vec::new(),
+            listener_max_events_per_author_per_minute: None,
+            listener_idle_resubscribe_secs: default_nip46_listener_idle_resubscribe_secs(),
         }
     }
 }
@@ -277,10 +494,36 @@ pub struct BridgeConfig {
     pub publish_initial_backoff_millis: u64,
     #[serde(default = "default_bridge_publish_max_backoff_millis")]
     pub publish_max_backoff_millis: u64,
+    #[serde(default = "default_bridge_publish_reconnect_failed")]
+    pub publish_reconnect_failed: bool,
     #[serde(default = "default_bridge_job_status_retention")]
     pub job_status_retention: usize,
     #[serde(default = "default_bridge_state_path")]
     pub state_path: PathBuf,
+    #[serde(default)]
+    pub self_sign_kinds: Option<Vec<u32>>,
+    #[serde(default)]
+    pub trim_content: bool,
+    #[serde(default)]
+    pub reject_control_chars: bool,
+    #[serde(default = "default_bridge_listing_max_images")]
+    pub listing_max_images: usize,
+    #[serde(default)]
+    pub listing_verify_references: bool,
+    #[serde(default = "default_bridge_max_event_bytes")]
+    pub max_event_bytes: usize,
+    #[serde(default)]
+    pub trace_relay_messages: bool,
+    #[serde(default = "default_bridge_max_tags")]
+    pub max_tags: usize,
+    #[serde(default)]
+    pub fallback_relays: Vec<String>,
+    #[serde(default = "default_bridge_min_relays_for_publish")]
+    pub min_relays_for_publish: usize,
+    #[serde(default)]
+    pub kind_aliases: HashMap<u32, u32>,
+    #[serde(default = "default_bridge_relay_error_history")]
+    pub relay_error_history: usize,
 }
 
 impl Default for BridgeConfig {
@@ -294,8 +537,21 @@ impl Default for BridgeConfig {
             publish_max_attempts: default_bridge_publish_max_attempts(),
             publish_initial_backoff_millis: default_bridge_publish_initial_backoff_millis(),
             publish_max_backoff_millis: default_bridge_publish_max_backoff_millis(),
+            publish_reconnect_failed: default_bridge_publish_reconnect_failed(),
             job_status_retention: default_bridge_job_status_retention(),
             state_path: default_bridge_state_path(),
+            self_sign_kinds: None,
+            trim_content: false,
+            reject_control_chars: false,
+            listing_max_images: default_bridge_listing_max_images(),
+            listing_verify_references: false,
+            max_event_bytes: default_bridge_max_event_bytes(),
+            trace_relay_messages: false,
+            max_tags: default_bridge_max_tags(),
+            fallback_relays: Vec::new(),
+            min_relays_for_publish: default_bridge_min_relays_for_publish(),
+            kind_aliases: HashMap::new(),
+            relay_error_history: default_bridge_relay_error_history(),
         }
     }
 }
@@ -308,6 +564,12 @@ impl BridgeConfig {
             .filter(|token| !token.is_empty())
     }
 
+    pub fn allows_self_sign_kind(&self, kind: u32) -> bool {
+        self.self_sign_kinds
+            .as_ref()
+            .is_none_or(|kinds| kinds.contains(&kind))
+    }
+
     pub fn validate(&self) -> Result<()> {
         if self.enabled && self.bearer_token().is_none() {
             bail!("bridge bearer_token is required when bridge ingress is enabled");
@@ -332,6 +594,21 @@ pub struct RpcConfig {
     pub message_buffer_capacity: u32,
     #[serde(default)]
     pub batch_request_limit: Option<u32>,
+    #[serde(default = "default_shutdown_drain_secs")]
+    pub shutdown_drain_secs: u64,
+    #[serde(default)]
+    pub method_concurrency: HashMap<String, usize>,
+    #[serde(default = "default_method_concurrency_policy")]
+    pub method_concurrency_policy: MethodConcurrencyPolicy,
+    #[serde(default = "default_suggest_unknown_methods")]
+    pub suggest_unknown_methods: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MethodConcurrencyPolicy {
+    Reject,
+    Queue,
 }
 
 impl Default for RpcConfig {
@@ -344,6 +621,10 @@ impl Default for RpcConfig {
             max_subscriptions_per_connection: default_max_subscriptions_per_connection(),
             message_buffer_capacity: default_message_buffer_capacity(),
             batch_request_limit: None,
+            shutdown_drain_secs: default_shutdown_drain_secs(),
+            method_concurrency: HashMap::new(),
+            method_concurrency_policy: default_method_concurrency_policy(),
+            suggest_unknown_methods: default_suggest_unknown_methods(),
         }
     }
 }
@@ -357,11 +638,35 @@ pub struct Configuration {
     #[serde(default)]
     pub rpc_addr: Option<String>,
     #[serde(default)]
+    pub identity_source: IdentitySource,
+    #[serde(default)]
+    pub metadata_pubkey_mismatch: MetadataPubkeyMismatchPolicy,
+    #[serde(default)]
+    pub merge_startup_metadata: bool,
+    #[serde(default)]
+    pub max_relays: Option<usize>,
+    #[serde(default)]
     pub nip46: Nip46Config,
     #[serde(default)]
     pub bridge: BridgeConfig,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentitySource {
+    #[default]
+    File,
+    Env,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataPubkeyMismatchPolicy {
+    #[default]
+    Fail,
+    Warn,
+}
+
 impl Configuration {
     pub fn rpc_addr(&self) -> &str {
         self.rpc_addr.as_deref().unwrap_or(self.rpc.addr.as_str())
@@ -369,6 +674,25 @@ impl Configuration {
 
     pub fn validate(&self) -> Result<()> {
         self.bridge.validate()?;
+        if let Some(max_relays) = self.max_relays
+            && self.service.relays.len() > max_relays
+        {
+            bail!(
+                "config.relays lists {} relays, exceeding max_relays {max_relays}",
+                self.service.relays.len()
+            );
+        }
+        for (field, relays) in [
+            ("relays", &self.nip46.relays),
+            ("listener_relays", &self.nip46.listener_relays),
+        ] {
+            if let Some(relay) = relays
+                .iter()
+                .find(|relay| !self.service.relays.contains(relay))
+            {
+                bail!("nip46.{field} relay {relay} must also be listed in config.relays");
+            }
+        }
         Ok(())
     }
 }
@@ -390,8 +714,10 @@ mod tests {
     use std::path::PathBuf;
 
     use super::{
-        BridgeConfig, BridgeDeliveryPolicy, Configuration, Nip46Config, RpcConfig,
-        load_settings_from_path_with_resolver,
+        BridgeConfig, BridgeDeliveryPolicy, Configuration, IdentitySource,
+        MetadataPubkeyMismatchPolicy, MethodConcurrencyPolicy, Nip46Config, RpcConfig,
+        load_settings_from_path_with_resolver, merge_config_layer, normalize_relay_url,
+        parse_kind_aliases,
     };
     use crate::app::paths::{
         default_runtime_paths_for_process, resolve_runtime_paths_with_resolver,
@@ -431,9 +757,16 @@ mod tests {
     fn nip46_defaults_are_expected() {
         let cfg = Nip46Config::default();
         assert_eq!(cfg.session_ttl_secs, 900);
+        assert!(cfg.session_idle_timeout_secs.is_none());
         assert!(cfg.perms.is_empty());
         assert!(!cfg.public_jsonrpc_enabled);
         assert!(cfg.nostrconnect_url.is_none());
+        assert!(cfg.relays.is_empty());
+        assert!(cfg.listener_relays.is_empty());
+        assert!(cfg.listener_allowed_authors.is_empty());
+        assert!(cfg.listener_banned_authors.is_empty());
+        assert!(cfg.listener_max_events_per_author_per_minute.is_none());
+        assert_eq!(cfg.listener_idle_resubscribe_secs, 300);
     }
 
     #[test]
@@ -446,6 +779,13 @@ mod tests {
         assert_eq!(cfg.max_subscriptions_per_connection, 1024);
         assert_eq!(cfg.message_buffer_capacity, 1024);
         assert!(cfg.batch_request_limit.is_none());
+        assert_eq!(cfg.shutdown_drain_secs, 10);
+        assert!(cfg.method_concurrency.is_empty());
+        assert_eq!(
+            cfg.method_concurrency_policy,
+            MethodConcurrencyPolicy::Reject
+        );
+        assert!(cfg.suggest_unknown_methods);
     }
 
     #[test]
@@ -460,8 +800,33 @@ mod tests {
         assert_eq!(cfg.publish_max_attempts, 1);
         assert_eq!(cfg.publish_initial_backoff_millis, 250);
         assert_eq!(cfg.publish_max_backoff_millis, 2_000);
+        assert!(cfg.publish_reconnect_failed);
         assert_eq!(cfg.job_status_retention, 256);
         assert_eq!(cfg.state_path, paths.bridge_state_path);
+        assert!(cfg.self_sign_kinds.is_none());
+        assert!(!cfg.trim_content);
+        assert!(!cfg.reject_control_chars);
+        assert_eq!(cfg.listing_max_images, 16);
+        assert!(!cfg.listing_verify_references);
+        assert_eq!(cfg.max_event_bytes, 256 * 1024);
+        assert!(!cfg.trace_relay_messages);
+        assert_eq!(cfg.max_tags, 2_000);
+        assert_eq!(cfg.min_relays_for_publish, 1);
+        assert!(cfg.kind_aliases.is_empty());
+        assert!(cfg.fallback_relays.is_empty());
+        assert_eq!(cfg.relay_error_history, 20);
+    }
+
+    #[test]
+    fn bridge_self_sign_kinds_allow_all_when_unset() {
+        let cfg = BridgeConfig::default();
+        assert!(cfg.allows_self_sign_kind(1));
+        let cfg = BridgeConfig {
+            self_sign_kinds: Some(vec![30402]),
+            ..BridgeConfig::default()
+        };
+        assert!(cfg.allows_self_sign_kind(30402));
+        assert!(!cfg.allows_self_sign_kind(1));
     }
 
     #[test]
@@ -473,6 +838,10 @@ mod tests {
                 ..RpcConfig::default()
             },
             rpc_addr: None,
+            identity_source: IdentitySource::File,
+            metadata_pubkey_mismatch: MetadataPubkeyMismatchPolicy::Fail,
+            merge_startup_metadata: false,
+            max_relays: None,
             nip46: Nip46Config::default(),
             bridge: BridgeConfig::default(),
         };
@@ -481,6 +850,64 @@ mod tests {
         assert_eq!(cfg.rpc_addr(), "127.0.0.1:2222");
     }
 
+    #[test]
+    fn nip46_relays_must_be_configured_relays() {
+        let mut service = service_config();
+        service.relays = vec!["wss://relay-a.example.com".to_string()];
+        let mut cfg = Configuration {
+            service,
+            rpc: RpcConfig::default(),
+            rpc_addr: None,
+            identity_source: IdentitySource::File,
+            metadata_pubkey_mismatch: MetadataPubkeyMismatchPolicy::Fail,
+            merge_startup_metadata: false,
+            max_relays: None,
+            nip46: Nip46Config {
+                relays: vec!["wss://relay-a.example.com".to_string()],
+                ..Nip46Config::default()
+            },
+            bridge: BridgeConfig::default(),
+        };
+        cfg.validate().expect("subset of configured relays");
+        cfg.nip46.relays = vec!["wss://relay-b.example.com".to_string()];
+        let err = cfg.validate().expect_err("unknown nip46 relay");
+        assert!(err.to_string().contains("wss://relay-b.example.com"));
+
+        cfg.nip46.relays = Vec::new();
+        cfg.nip46.listener_relays = vec!["wss://relay-a.example.com".to_string()];
+        cfg.validate()
+            .expect("listener relays within configured relays");
+        cfg.nip46.listener_relays = vec!["wss://signer.example.com".to_string()];
+        let err = cfg.validate().expect_err("unknown listener relay");
+        assert!(err.to_string().contains("nip46.listener_relays"));
+    }
+
+    #[test]
+    fn relays_past_max_relays_are_rejected() {
+        let mut service = service_config();
+        service.relays = vec![
+            "wss://relay-a.example.com".to_string(),
+            "wss://relay-b.example.com".to_string(),
+        ];
+        let mut cfg = Configuration {
+            service,
+            rpc: RpcConfig::default(),
+            rpc_addr: None,
+            identity_source: IdentitySource::File,
+            metadata_pubkey_mismatch: MetadataPubkeyMismatchPolicy::Fail,
+            merge_startup_metadata: false,
+            max_relays: Some(2),
+            nip46: Nip46Config::default(),
+            bridge: BridgeConfig::default(),
+        };
+        cfg.validate().expect("within max_relays");
+        cfg.service
+            .relays
+            .push("wss://relay-c.example.com".to_string());
+        let err = cfg.validate().expect_err("past max_relays");
+        assert!(err.to_string().contains("max_relays 2"));
+    }
+
     #[test]
     fn bridge_validation_requires_bearer_token_when_enabled() {
         let err = BridgeConfig {
@@ -614,6 +1041,7 @@ bearer_token = "change-me"
 
         let settings = load_settings_from_path_with_resolver(
             &config_path,
+            &[],
             &linux_resolver("/home/treesap"),
             RadrootsPathProfile::InteractiveUser,
             None,
@@ -632,6 +1060,171 @@ bearer_token = "change-me"
         );
     }
 
+    #[test]
+    fn merge_config_layer_overrides_scalars_and_replaces_relay_lists() {
+        let mut base = serde_json::json!({
+            "metadata": { "name": "radrootsd-base", "about": "base node" },
+            "config": {
+                "relays": ["wss://relay-a.example.com", "wss://relay-b.example.com"],
+                "rpc": { "addr": "127.0.0.1:7070", "max_connections": 50 },
+            }
+        });
+        let overlay = serde_json::json!({
+            "metadata": { "name": "radrootsd-prod" },
+            "config": {
+                "relays": ["wss://relay-c.example.com"],
+                "rpc": { "addr": "0.0.0.0:7070" },
+            }
+        });
+
+        merge_config_layer(&mut base, overlay);
+
+        assert_eq!(base["metadata"]["name"], "radrootsd-prod");
+        assert_eq!(base["metadata"]["about"], "base node");
+        assert_eq!(
+            base["config"]["relays"],
+            serde_json::json!(["wss://relay-c.example.com"])
+        );
+        assert_eq!(base["config"]["rpc"]["addr"], "0.0.0.0:7070");
+        assert_eq!(base["config"]["rpc"]["max_connections"], 50);
+    }
+
+    #[test]
+    fn load_settings_applies_overlay_files_in_order() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let base_path = temp.path().join("base.toml");
+        let overlay_path = temp.path().join("prod.toml");
+        std::fs::write(
+            &base_path,
+            r#"
+[metadata]
+name = "radrootsd-base"
+
+[config]
+relays = ["wss://relay-a.example.com", "wss://relay-b.example.com"]
+
+[config.rpc]
+addr = "127.0.0.1:7070"
+"#,
+        )
+        .expect("write base config");
+        std::fs::write(
+            &overlay_path,
+            r#"
+[config]
+relays = ["wss://relay-c.example.com"]
+
+[config.rpc]
+max_connections = 10
+"#,
+        )
+        .expect("write overlay config");
+
+        let settings = load_settings_from_path_with_resolver(
+            &base_path,
+            &[overlay_path],
+            &linux_resolver("/home/treesap"),
+            RadrootsPathProfile::InteractiveUser,
+            None,
+        )
+        .expect("load layered settings");
+
+        assert_eq!(
+            settings.config.service.relays,
+            vec!["wss://relay-c.example.com".to_string()]
+        );
+        assert_eq!(settings.config.rpc.addr, "127.0.0.1:7070");
+        assert_eq!(settings.config.rpc.max_connections, 10);
+    }
+
+    #[test]
+    fn normalize_relay_url_maps_equivalent_forms_to_one_url() {
+        for raw in [
+            "wss://relay.example.com",
+            "wss://relay.example.com/",
+            "WSS://Relay.Example.COM",
+            "wss://relay.example.com:443",
+            " wss://relay.example.com:443/ ",
+        ] {
+            assert_eq!(
+                normalize_relay_url(raw).expect("normalize"),
+                "wss://relay.example.com"
+            );
+        }
+        assert_eq!(
+            normalize_relay_url("ws://127.0.0.1:80/").expect("normalize"),
+            "ws://127.0.0.1"
+        );
+        assert_eq!(
+            normalize_relay_url("ws://127.0.0.1:8080").expect("normalize"),
+            "ws://127.0.0.1:8080"
+        );
+        assert_eq!(
+            normalize_relay_url("wss://relay.example.com/inbox/").expect("normalize"),
+            "wss://relay.example.com/inbox/"
+        );
+    }
+
+    #[test]
+    fn normalize_relay_url_rejects_non_websocket_urls() {
+        assert!(normalize_relay_url("https://relay.example.com").is_err());
+        assert!(normalize_relay_url("relay.example.com").is_err());
+    }
+
+    #[test]
+    fn parse_kind_aliases_maps_string_keys_to_kinds() {
+        let aliases = parse_kind_aliases(std::collections::HashMap::from([(
+            " 30400 ".to_string(),
+            30402,
+        )]))
+        .expect("aliases");
+        assert_eq!(aliases.get(&30400), Some(&30402));
+
+        assert!(parse_kind_aliases([("listing".to_string(), 30402)].into()).is_err());
+        assert!(parse_kind_aliases([("30402".to_string(), 30402)].into()).is_err());
+    }
+
+    #[test]
+    fn load_settings_normalizes_and_dedupes_relays() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let config_path = temp.path().join("radrootsd.toml");
+        std::fs::write(
+            &config_path,
+            r#"
+[metadata]
+name = "radrootsd-test"
+
+[config]
+relays = ["wss://relay.example.com/", "WSS://relay.example.com:443", "ws://127.0.0.1:8080"]
+
+[config.nip46]
+relays = ["wss://Relay.Example.com"]
+"#,
+        )
+        .expect("write config");
+
+        let settings = load_settings_from_path_with_resolver(
+            &config_path,
+            &[],
+            &linux_resolver("/home/treesap"),
+            RadrootsPathProfile::InteractiveUser,
+            None,
+        )
+        .expect("load settings");
+
+        assert_eq!(
+            settings.config.service.relays,
+            vec![
+                "wss://relay.example.com".to_string(),
+                "ws://127.0.0.1:8080".to_string()
+            ]
+        );
+        assert_eq!(
+            settings.config.nip46.relays,
+            vec!["wss://relay.example.com".to_string()]
+        );
+    }
+
     #[test]
     fn runtime_contract_output_matches_interactive_user_contract() {
         let contract = runtime_contract_with_resolver(
@@ -1,9 +1,11 @@
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result, anyhow};
 use radroots_identity::{IdentityError, RadrootsIdentity, RadrootsIdentityFile};
+use radroots_nostr::prelude::{RadrootsNostrKeys, RadrootsNostrSecretKey};
 
 const RADROOTSD_IDENTITY_KEY_SLOT: &str = "radrootsd_identity";
+const RADROOTSD_SECRET_KEY_ENV: &str = "RADROOTSD_SECRET_KEY";
 
 #[cfg(test)]
 pub fn encrypted_identity_key_path(path: impl AsRef<Path>) -> PathBuf {
@@ -39,6 +41,23 @@ pub fn load_encrypted_identity(path: impl AsRef<Path>) -> Result<RadrootsIdentit
     Ok(RadrootsIdentity::try_from(file)?)
 }
 
+pub fn load_env_identity() -> Result<RadrootsIdentity> {
+    load_env_identity_with(|name| std::env::var(name).ok())
+}
+
+fn load_env_identity_with(lookup: impl Fn(&str) -> Option<String>) -> Result<RadrootsIdentity> {
+    let secret_key = lookup(RADROOTSD_SECRET_KEY_ENV)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .with_context(|| {
+            format!("identity_source is env but {RADROOTSD_SECRET_KEY_ENV} is not set")
+        })?;
+    // The parse error is dropped so the secret never reaches logs or error chains.
+    let secret_key = RadrootsNostrSecretKey::parse(&secret_key)
+        .map_err(|_| anyhow!("{RADROOTSD_SECRET_KEY_ENV} is not a valid secret key"))?;
+    Ok(RadrootsIdentity::new(RadrootsNostrKeys::new(secret_key)))
+}
+
 fn resolved_identity_path(path: Option<&Path>) -> PathBuf {
     path.map(Path::to_path_buf).unwrap_or_else(|| {
         crate::app::paths::default_identity_path_for_process()
@@ -48,7 +67,12 @@ fn resolved_identity_path(path: Option<&Path>) -> PathBuf {
 
 #[cfg(test)]
 mod tests {
-    use super::{encrypted_identity_key_path, load_service_identity};
+    use radroots_nostr::prelude::RadrootsNostrKeys;
+
+    use super::{
+        RADROOTSD_SECRET_KEY_ENV, encrypted_identity_key_path, load_env_identity_with,
+        load_service_identity,
+    };
 
     #[test]
     fn load_service_identity_generates_encrypted_identity_artifacts() {
@@ -75,4 +99,27 @@ mod tests {
             .expect_err("missing wrapping key should fail");
         assert!(err.to_string().contains("identity"));
     }
+
+    #[test]
+    fn load_env_identity_derives_pubkey_from_secret_key() {
+        let keys = RadrootsNostrKeys::generate();
+        let secret_key = keys.secret_key().to_secret_hex();
+
+        let identity = load_env_identity_with(|name| {
+            (name == RADROOTSD_SECRET_KEY_ENV).then(|| secret_key.clone())
+        })
+        .expect("env identity");
+
+        assert_eq!(identity.public_key(), keys.public_key());
+    }
+
+    #[test]
+    fn load_env_identity_errors_without_leaking_value() {
+        let missing = load_env_identity_with(|_| None).expect_err("missing env secret");
+        assert!(missing.to_string().contains(RADROOTSD_SECRET_KEY_ENV));
+
+        let invalid = load_env_identity_with(|_| Some("not-a-secret-key".to_string()))
+            .expect_err("invalid env secret");
+        assert!(!format!("{invalid:#}").contains("not-a-secret-key"));
+    }
 }
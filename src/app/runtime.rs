@@ -4,20 +4,25 @@ use radroots_identity::RadrootsIdentity;
 use std::time::Duration;
 use tracing::{info, warn};
 
-use crate::app::identity_storage::load_service_identity;
+use crate::app::identity_storage::{load_env_identity, load_service_identity};
 use crate::app::{cli, config, paths};
 use crate::core::Radrootsd;
 use crate::transport::jsonrpc;
 #[cfg(not(test))]
 use crate::transport::nostr::listener::spawn_nip46_listener;
 #[cfg(not(test))]
+use crate::transport::nostr::relay_errors::spawn_relay_error_log;
+#[cfg(not(test))]
+use crate::transport::nostr::relay_trace::spawn_relay_trace;
+#[cfg(not(test))]
 use anyhow::Context;
 #[cfg(not(test))]
 use clap::Parser;
 use radroots_events::kinds::KIND_LISTING;
 use radroots_events::profile::RadrootsProfileType;
 use radroots_nostr::prelude::{
-    RadrootsNostrApplicationHandlerSpec, RadrootsNostrKind,
+    RadrootsNostrApplicationHandlerSpec, RadrootsNostrClient, RadrootsNostrFilter,
+    RadrootsNostrKind, RadrootsNostrMetadata, RadrootsNostrPublicKey,
     radroots_nostr_bootstrap_service_presence,
 };
 use std::path::PathBuf;
@@ -44,6 +49,14 @@ static RUN_START_RPC_HOOK: std::sync::OnceLock<
 enum RunWaitOutcome {
     Shutdown,
     Stopped,
+    Reload,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct SettingsReloadPlan {
+    added_relays: Vec<String>,
+    removed_relays: Vec<String>,
+    restart_required: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -137,14 +150,9 @@ fn load_args_and_settings() -> Result<(cli::Args, config::Settings)> {
     #[cfg(not(test))]
     {
         let args = cli::Args::try_parse().map_err(radroots_runtime::RuntimeCliError::from)?;
-        let config_path = args
-            .service
-            .config
-            .clone()
-            .map(Ok)
-            .unwrap_or_else(paths::default_config_path_for_process)?;
-        let settings =
-            config::load_settings_from_path(&config_path).context("load configuration")?;
+        let config_path = resolve_config_path(&args)?;
+        let settings = config::load_settings_from_paths(&config_path, &args.config_overlays)
+            .context("load configuration")?;
         radroots_runtime::init_with_logs_dir(
             std::path::Path::new(settings.config.service.logs_dir.as_str()),
             None,
@@ -153,6 +161,163 @@ fn load_args_and_settings() -> Result<(cli::Args, config::Settings)> {
     }
 }
 
+#[cfg(not(test))]
+fn resolve_config_path(args: &cli::Args) -> Result<PathBuf> {
+    args.service
+        .config
+        .clone()
+        .map(Ok)
+        .unwrap_or_else(paths::default_config_path_for_process)
+}
+
+#[cfg(not(test))]
+fn reload_settings(args: &cli::Args) -> Result<config::Settings> {
+    let config_path = resolve_config_path(args)?;
+    let settings = config::load_settings_from_paths(&config_path, &args.config_overlays)
+        .context("reload configuration")?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+fn reload_settings(_args: &cli::Args) -> Result<config::Settings> {
+    Err(anyhow::anyhow!(
+        "configuration reload is not available in tests"
+    ))
+}
+
+fn settings_reload_plan(current: &config::Settings, next: &config::Settings) -> SettingsReloadPlan {
+    let current_relays = &current.config.service.relays;
+    let next_relays = &next.config.service.relays;
+    let mut restart_required = Vec::new();
+    if current.config.rpc_addr() != next.config.rpc_addr()
+        || serialized_differs(&current.config.rpc, &next.config.rpc)
+    {
+        restart_required.push("config.rpc".to_string());
+    }
+    if current.config.identity_source != next.config.identity_source {
+        restart_required.push("config.identity_source".to_string());
+    }
+    if current.config.metadata_pubkey_mismatch != next.config.metadata_pubkey_mismatch {
+        restart_required.push("config.metadata_pubkey_mismatch".to_string());
+    }
+    if current.config.merge_startup_metadata != next.config.merge_startup_metadata {
+        restart_required.push("config.merge_startup_metadata".to_string());
+    }
+    if current.config.service.logs_dir != next.config.service.logs_dir {
+        restart_required.push("config.logs_dir".to_string());
+    }
+    if current.config.service.nip89_identifier != next.config.service.nip89_identifier
+        || current.config.service.nip89_extra_tags != next.config.service.nip89_extra_tags
+    {
+        restart_required.push("config.nip89".to_string());
+    }
+    if current.config.max_relays != next.config.max_relays {
+        restart_required.push("config.max_relays".to_string());
+    }
+    changed_fields(
+        "config.bridge",
+        &current.config.bridge,
+        &next.config.bridge,
+        &mut restart_required,
+    );
+    changed_fields(
+        "config.nip46",
+        &current.config.nip46,
+        &next.config.nip46,
+        &mut restart_required,
+    );
+    if serialized_differs(&current.metadata, &next.metadata) {
+        restart_required.push("metadata".to_string());
+    }
+    SettingsReloadPlan {
+        added_relays: next_relays
+            .iter()
+            .filter(|relay| !current_relays.contains(relay))
+            .cloned()
+            .collect(),
+        removed_relays: current_relays
+            .iter()
+            .filter(|relay| !next_relays.contains(relay))
+            .cloned()
+            .collect(),
+        restart_required,
+    }
+}
+
+fn serialized_differs<T: serde::Serialize>(current: &T, next: &T) -> bool {
+    serde_json::to_value(current).ok() != serde_json::to_value(next).ok()
+}
+
+// The bridge and nip46 configs are captured by the RPC context and listener at
+// startup, so every changed field is reported by name as needing a restart.
+fn changed_fields<T: serde::Serialize>(
+    section: &str,
+    current: &T,
+    next: &T,
+    restart_required: &mut Vec<String>,
+) {
+    let current = serde_json::to_value(current).unwrap_or_default();
+    let next = serde_json::to_value(next).unwrap_or_default();
+    match (current, next) {
+        (serde_json::Value::Object(current), serde_json::Value::Object(next)) => {
+            let mut fields = current.keys().chain(next.keys()).collect::<Vec<_>>();
+            fields.sort();
+            fields.dedup();
+            restart_required.extend(
+                fields
+                    .into_iter()
+                    .filter(|field| current.get(*field) != next.get(*field))
+                    .map(|field| format!("{section}.{field}")),
+            );
+        }
+        (current, next) if current != next => restart_required.push(section.to_string()),
+        _ => {}
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn apply_settings_reload(radrootsd: &Radrootsd, plan: &SettingsReloadPlan) -> Result<()> {
+    for relay in plan.added_relays.iter() {
+        radrootsd.client.add_relay(relay).await?;
+    }
+    for relay in plan.removed_relays.iter() {
+        radrootsd.client.remove_relay(relay).await?;
+    }
+    if !plan.added_relays.is_empty() {
+        radrootsd.client.connect().await;
+    }
+    Ok(())
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn reload_runtime_settings(
+    args: &cli::Args,
+    radrootsd: &Radrootsd,
+    current: &config::Settings,
+) -> Result<(config::Settings, Vec<String>)> {
+    let next = reload_settings(args)?;
+    let plan = settings_reload_plan(current, &next);
+    apply_settings_reload(radrootsd, &plan).await?;
+    info!(
+        added_relays = ?plan.added_relays,
+        removed_relays = ?plan.removed_relays,
+        "Reloaded configuration"
+    );
+    if !plan.restart_required.is_empty() {
+        warn!(
+            sections = ?plan.restart_required,
+            "Configuration changes require a restart to take effect"
+        );
+    }
+    Ok((applied_settings(current, &next), plan.restart_required))
+}
+
+fn applied_settings(current: &config::Settings, next: &config::Settings) -> config::Settings {
+    let mut applied = current.clone();
+    applied.config.service.relays = next.config.service.relays.clone();
+    applied
+}
+
 fn runtime_startup_report(
     args: &cli::Args,
     settings: &config::Settings,
@@ -267,6 +432,7 @@ async fn bootstrap_presence(
     identity: &RadrootsIdentity,
     metadata: &radroots_nostr::prelude::RadrootsNostrMetadata,
     handler_spec: &RadrootsNostrApplicationHandlerSpec,
+    timeout: Duration,
 ) -> Result<()> {
     let bootstrap_result: Result<()> = match take_bootstrap_hook_result() {
         Some(result) => result.map_err(anyhow::Error::msg),
@@ -276,7 +442,7 @@ async fn bootstrap_presence(
             Some(RadrootsProfileType::Radrootsd),
             metadata,
             handler_spec,
-            Duration::from_secs(5),
+            timeout,
         )
         .await
         .map(|_| ())
@@ -294,7 +460,14 @@ async fn publish_service_presence(
     service_cfg: radroots_runtime::RadrootsNostrServiceConfig,
     bridge_config: config::BridgeConfig,
     nip46_config: config::Nip46Config,
+    merge_metadata: bool,
 ) -> Result<()> {
+    let timeout = Duration::from_secs(bridge_config.connect_timeout_secs);
+    let metadata = if merge_metadata {
+        startup_metadata(&client, identity.public_key(), metadata, timeout).await
+    } else {
+        metadata
+    };
     let kinds = service_presence_kinds(&bridge_config);
     let handler_spec = RadrootsNostrApplicationHandlerSpec {
         kinds,
@@ -304,7 +477,47 @@ async fn publish_service_presence(
         relays: service_cfg.relays.clone(),
         nostrconnect_url: nip46_config.nostrconnect_url.clone(),
     };
-    bootstrap_presence(&client, &identity, &metadata, &handler_spec).await
+    bootstrap_presence(&client, &identity, &metadata, &handler_spec, timeout).await
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn startup_metadata(
+    client: &RadrootsNostrClient,
+    pubkey: RadrootsNostrPublicKey,
+    configured: RadrootsNostrMetadata,
+    timeout: Duration,
+) -> RadrootsNostrMetadata {
+    let filter = RadrootsNostrFilter::new()
+        .author(pubkey)
+        .kind(RadrootsNostrKind::Metadata)
+        .limit(1);
+    client.connect().await;
+    let existing = match client.fetch_events(filter, timeout).await {
+        Ok(events) => events.into_iter().max_by_key(|event| event.created_at),
+        Err(err) => {
+            warn!("Failed to fetch existing metadata, publishing configured metadata: {err}");
+            None
+        }
+    };
+    match existing {
+        Some(existing) => merge_metadata_over(&existing.content, configured),
+        None => configured,
+    }
+}
+
+// Configured fields win; fields only present in the relay copy are kept.
+fn merge_metadata_over(
+    existing_content: &str,
+    configured: RadrootsNostrMetadata,
+) -> RadrootsNostrMetadata {
+    let Ok(serde_json::Value::Object(mut merged)) = serde_json::from_str(existing_content) else {
+        return configured;
+    };
+    let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(&configured) else {
+        return configured;
+    };
+    merged.extend(fields.into_iter().filter(|(_, value)| !value.is_null()));
+    serde_json::from_value(serde_json::Value::Object(merged)).unwrap_or(configured)
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -315,6 +528,7 @@ async fn maybe_publish_service_presence(
     service_cfg: radroots_runtime::RadrootsNostrServiceConfig,
     bridge_config: config::BridgeConfig,
     nip46_config: config::Nip46Config,
+    merge_metadata: bool,
 ) {
     #[cfg(test)]
     {
@@ -325,6 +539,7 @@ async fn maybe_publish_service_presence(
             service_cfg,
             bridge_config,
             nip46_config,
+            merge_metadata,
         )
         .await;
         if let Err(err) = result {
@@ -344,6 +559,7 @@ async fn maybe_publish_service_presence(
             service_cfg,
             bridge_config,
             nip46_config,
+            merge_metadata,
         )
         .await;
         if let Err(err) = result {
@@ -363,16 +579,38 @@ fn spawn_nip46_listener_io(radrootsd: Radrootsd) {
 #[cfg(test)]
 fn spawn_nip46_listener_io(_radrootsd: Radrootsd) {}
 
+#[cfg(not(test))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn spawn_relay_trace_io(client: radroots_nostr::prelude::RadrootsNostrClient) {
+    spawn_relay_trace(client);
+}
+
+#[cfg(test)]
+fn spawn_relay_trace_io(_client: radroots_nostr::prelude::RadrootsNostrClient) {}
+
+#[cfg(not(test))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn spawn_relay_error_log_io(radrootsd: &Radrootsd) {
+    spawn_relay_error_log(
+        radrootsd.client.clone(),
+        radrootsd.bridge_relay_errors.clone(),
+    );
+}
+
+#[cfg(test)]
+fn spawn_relay_error_log_io(_radrootsd: &Radrootsd) {}
+
 #[cfg(test)]
 async fn start_rpc_io(
     state: Radrootsd,
     addr: std::net::SocketAddr,
     rpc_cfg: &config::RpcConfig,
+    in_flight: jsonrpc::InFlightRequests,
 ) -> Result<ServerHandle> {
     if let Some(result) = take_start_rpc_hook_result() {
         return result.map_err(anyhow::Error::msg);
     }
-    jsonrpc::start_rpc(state, addr, rpc_cfg).await
+    jsonrpc::start_rpc(state, addr, rpc_cfg, in_flight).await
 }
 
 #[cfg(not(test))]
@@ -381,8 +619,9 @@ async fn start_rpc_io(
     state: Radrootsd,
     addr: std::net::SocketAddr,
     rpc_cfg: &config::RpcConfig,
+    in_flight: jsonrpc::InFlightRequests,
 ) -> Result<ServerHandle> {
-    jsonrpc::start_rpc(state, addr, rpc_cfg).await
+    jsonrpc::start_rpc(state, addr, rpc_cfg, in_flight).await
 }
 
 #[cfg(test)]
@@ -399,10 +638,32 @@ async fn wait_for_shutdown_or_stopped(handle: ServerHandle) -> RunWaitOutcome {
 async fn wait_for_shutdown_or_stopped(handle: ServerHandle) -> RunWaitOutcome {
     tokio::select! {
         _ = radroots_runtime::shutdown_signal() => RunWaitOutcome::Shutdown,
+        _ = reload_signal() => RunWaitOutcome::Reload,
         _ = handle.stopped() => RunWaitOutcome::Stopped,
     }
 }
 
+#[cfg(all(not(test), unix))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn reload_signal() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    match signal(SignalKind::hangup()) {
+        Ok(mut hangup) => {
+            hangup.recv().await;
+        }
+        Err(err) => {
+            warn!("Failed to install SIGHUP handler: {err}");
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+#[cfg(all(not(test), not(unix)))]
+async fn reload_signal() {
+    std::future::pending::<()>().await;
+}
+
 pub async fn run() -> Result<()> {
     let (args, settings): (cli::Args, config::Settings) = load_args_and_settings()?;
     settings.config.validate()?;
@@ -418,9 +679,17 @@ pub async fn run() -> Result<()> {
 
     info!("Starting radrootsd");
 
-    let identity = load_service_identity(
-        args.service.identity.as_deref(),
-        args.service.allow_generate_identity,
+    let identity = match settings.config.identity_source {
+        config::IdentitySource::File => load_service_identity(
+            args.service.identity.as_deref(),
+            args.service.allow_generate_identity,
+        )?,
+        config::IdentitySource::Env => load_env_identity()?,
+    };
+    check_metadata_pubkey(
+        &settings.metadata,
+        &identity.public_key(),
+        settings.config.metadata_pubkey_mismatch,
     )?;
     let radrootsd = Radrootsd::new(
         identity.clone(),
@@ -429,10 +698,23 @@ pub async fn run() -> Result<()> {
         settings.config.nip46.clone(),
     );
     let radrootsd = radrootsd?;
+    radrootsd.set_settings(settings.clone());
 
+    if settings.config.bridge.trace_relay_messages {
+        spawn_relay_trace_io(radrootsd.client.clone());
+    }
+    if settings.config.bridge.relay_error_history > 0 {
+        spawn_relay_error_log_io(&radrootsd);
+    }
     for relay in settings.config.service.relays.iter() {
         radrootsd.client.add_relay(relay).await?;
     }
+    if !settings.config.bridge.fallback_relays.is_empty() {
+        for relay in settings.config.bridge.fallback_relays.iter() {
+            radrootsd.bridge_fallback_client.add_relay(relay).await?;
+        }
+        radrootsd.bridge_fallback_client.connect().await;
+    }
 
     if !settings.config.service.relays.is_empty() {
         maybe_publish_service_presence(
@@ -442,29 +724,118 @@ pub async fn run() -> Result<()> {
             settings.config.service.clone(),
             settings.config.bridge.clone(),
             settings.config.nip46.clone(),
+            settings.config.merge_startup_metadata,
         )
         .await;
+    }
 
+    if !settings.config.service.relays.is_empty() {
         spawn_nip46_listener_io(radrootsd.clone());
     }
 
     let addr: std::net::SocketAddr = settings.config.rpc_addr().parse()?;
-    let handle = start_rpc_io(radrootsd.clone(), addr, &settings.config.rpc).await?;
+    let in_flight = jsonrpc::InFlightRequests::new();
+    let handle = start_rpc_io(
+        radrootsd.clone(),
+        addr,
+        &settings.config.rpc,
+        in_flight.clone(),
+    )
+    .await?;
     info!("JSON-RPC listening on {addr}");
 
     let stop_handle = handle.clone();
-
-    match wait_for_shutdown_or_stopped(handle).await {
-        RunWaitOutcome::Shutdown => {
-            info!("Shutting down…");
-            let _ = stop_handle.stop();
+    let mut current_settings = settings;
+
+    loop {
+        match wait_for_shutdown_or_stopped(handle.clone()).await {
+            RunWaitOutcome::Shutdown => {
+                info!("Shutting down…");
+                let _ = stop_handle.stop();
+                drain_in_flight(&in_flight, current_settings.config.rpc.shutdown_drain_secs).await;
+                break;
+            }
+            RunWaitOutcome::Stopped => break,
+            RunWaitOutcome::Reload => {
+                match reload_runtime_settings(&args, &radrootsd, &current_settings).await {
+                    Ok((applied, pending_restart)) => {
+                        radrootsd.set_settings(applied.clone());
+                        radrootsd.set_pending_restart(pending_restart);
+                        current_settings = applied;
+                    }
+                    Err(err) => warn!("Failed to reload configuration, keeping previous: {err:#}"),
+                }
+            }
         }
-        RunWaitOutcome::Stopped => {}
     }
 
     Ok(())
 }
 
+async fn drain_in_flight(in_flight: &jsonrpc::InFlightRequests, drain_secs: u64) {
+    if in_flight.is_empty() {
+        return;
+    }
+    info!(
+        "Draining {} in-flight requests for up to {drain_secs}s",
+        in_flight.len()
+    );
+    if !in_flight.wait_idle(Duration::from_secs(drain_secs)).await {
+        warn!(
+            "Shutdown drain window elapsed with {} requests still in flight",
+            in_flight.len()
+        );
+    }
+}
+
+const METADATA_PUBKEY_FIELDS: &[&str] = &["pubkey", "npub"];
+
+fn check_metadata_pubkey(
+    metadata: &radroots_nostr::prelude::RadrootsNostrMetadata,
+    pubkey: &radroots_nostr::prelude::RadrootsNostrPublicKey,
+    policy: config::MetadataPubkeyMismatchPolicy,
+) -> Result<()> {
+    let mismatches = metadata_pubkey_mismatches(metadata, pubkey);
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+    let message = format!(
+        "metadata field(s) {} do not match service identity pubkey {}",
+        mismatches.join(", "),
+        pubkey.to_hex()
+    );
+    match policy {
+        config::MetadataPubkeyMismatchPolicy::Fail => Err(anyhow::anyhow!(message)),
+        config::MetadataPubkeyMismatchPolicy::Warn => {
+            warn!("{message}");
+            Ok(())
+        }
+    }
+}
+
+fn metadata_pubkey_mismatches(
+    metadata: &radroots_nostr::prelude::RadrootsNostrMetadata,
+    pubkey: &radroots_nostr::prelude::RadrootsNostrPublicKey,
+) -> Vec<&'static str> {
+    let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(metadata) else {
+        return Vec::new();
+    };
+    METADATA_PUBKEY_FIELDS
+        .iter()
+        .copied()
+        .filter(|field| {
+            fields
+                .get(*field)
+                .and_then(serde_json::Value::as_str)
+                .is_some_and(|value| {
+                    radroots_nostr::prelude::radroots_nostr_parse_pubkey(value.trim())
+                        .ok()
+                        .is_none_or(|parsed| &parsed != pubkey)
+                })
+        })
+        .collect()
+}
+
 fn service_presence_kinds(bridge_config: &config::BridgeConfig) -> Vec<u32> {
     let mut kinds = vec![RadrootsNostrKind::NostrConnect.as_u16() as u32];
     if bridge_config.enabled {
@@ -479,8 +850,9 @@ fn service_presence_kinds(bridge_config: &config::BridgeConfig) -> Vec<u32> {
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
     use super::{
-        RadrootsdRuntimeStartupReport, RunWaitOutcome, run, run_bootstrap_hook, run_load_hook,
-        run_start_rpc_hook, run_wait_hook, runtime_startup_report,
+        RadrootsdRuntimeStartupReport, RunWaitOutcome, SettingsReloadPlan, run, run_bootstrap_hook,
+        run_load_hook, run_start_rpc_hook, run_wait_hook, runtime_startup_report,
+        settings_reload_plan,
     };
     use crate::app::{cli, config, paths};
     use crate::core::Radrootsd;
@@ -535,6 +907,7 @@ mod tests {
                 identity: Some(path),
                 allow_generate_identity: allow_generate,
             },
+            config_overlays: Vec::new(),
         }
     }
 
@@ -555,6 +928,10 @@ mod tests {
                     ..config::RpcConfig::default()
                 },
                 rpc_addr: Some("127.0.0.1:0".to_string()),
+                identity_source: config::IdentitySource::File,
+                metadata_pubkey_mismatch: config::MetadataPubkeyMismatchPolicy::Fail,
+                merge_startup_metadata: false,
+                max_relays: None,
                 bridge: config::BridgeConfig::default(),
                 nip46: config::Nip46Config::default(),
             },
@@ -615,6 +992,7 @@ mod tests {
             state,
             "127.0.0.1:0".parse().expect("addr"),
             &settings.config.rpc,
+            jsonrpc::InFlightRequests::new(),
         )
         .await
         .expect("rpc handle")
@@ -794,6 +1172,27 @@ mod tests {
         cleanup_identity_artifacts(&path);
     }
 
+    #[tokio::test]
+    async fn run_keeps_running_when_reload_fails() {
+        let _guard = test_guard();
+        let path = unique_identity_path("reload-fail");
+        let args = args_for_identity(path.clone(), true);
+        let settings = settings_with_relays(Vec::new());
+        let handle = make_handle(&settings).await;
+        let _ = handle.stop();
+        *run_load_hook()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(Ok((args, settings)));
+        *run_start_rpc_hook()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(Ok(handle));
+        *run_wait_hook()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(RunWaitOutcome::Reload);
+        assert!(run().await.is_ok());
+        cleanup_identity_artifacts(&path);
+    }
+
     #[tokio::test]
     async fn run_starts_rpc_when_start_hook_is_not_set() {
         let _guard = test_guard();
@@ -810,6 +1209,85 @@ mod tests {
         cleanup_identity_artifacts(&path);
     }
 
+    #[test]
+    fn metadata_pubkey_mismatches_flag_disagreeing_fields() {
+        let identity = RadrootsIdentity::generate();
+        let other = RadrootsIdentity::generate();
+        let metadata = |fields: &str| -> RadrootsNostrMetadata {
+            serde_json::from_str(&format!(r#"{{"name":"radrootsd-test"{fields}}}"#))
+                .expect("metadata")
+        };
+
+        assert!(
+            super::metadata_pubkey_mismatches(&metadata(""), &identity.public_key()).is_empty()
+        );
+        assert!(
+            super::metadata_pubkey_mismatches(
+                &metadata(&format!(r#","pubkey":"{}""#, identity.public_key_hex())),
+                &identity.public_key()
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            super::metadata_pubkey_mismatches(
+                &metadata(&format!(
+                    r#","pubkey":"{}","npub":"not-a-key""#,
+                    other.public_key_hex()
+                )),
+                &identity.public_key()
+            ),
+            vec!["pubkey", "npub"]
+        );
+    }
+
+    #[test]
+    fn merge_metadata_over_keeps_fields_missing_from_config() {
+        let configured: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd","about":"configured about"}"#)
+                .expect("metadata");
+
+        let merged = super::merge_metadata_over(
+            r#"{"name":"old name","about":"old about","picture":"https://example.com/p.png","lud16":"farm@example.com"}"#,
+            configured.clone(),
+        );
+
+        let merged = serde_json::to_value(&merged).expect("json");
+        assert_eq!(merged["name"], "radrootsd");
+        assert_eq!(merged["about"], "configured about");
+        assert_eq!(merged["picture"], "https://example.com/p.png");
+        assert_eq!(merged["lud16"], "farm@example.com");
+
+        let unparsable = super::merge_metadata_over("not json", configured.clone());
+        assert_eq!(
+            serde_json::to_value(&unparsable).expect("json"),
+            serde_json::to_value(&configured).expect("json")
+        );
+    }
+
+    #[test]
+    fn check_metadata_pubkey_fails_or_warns_per_policy() {
+        let identity = RadrootsIdentity::generate();
+        let metadata: RadrootsNostrMetadata = serde_json::from_str(&format!(
+            r#"{{"name":"radrootsd-test","pubkey":"{}"}}"#,
+            RadrootsIdentity::generate().public_key_hex()
+        ))
+        .expect("metadata");
+
+        let err = super::check_metadata_pubkey(
+            &metadata,
+            &identity.public_key(),
+            config::MetadataPubkeyMismatchPolicy::Fail,
+        )
+        .expect_err("mismatch fails");
+        assert!(err.to_string().contains("pubkey"));
+        super::check_metadata_pubkey(
+            &metadata,
+            &identity.public_key(),
+            config::MetadataPubkeyMismatchPolicy::Warn,
+        )
+        .expect("mismatch warns");
+    }
+
     #[test]
     fn service_presence_kinds_include_listing_when_bridge_is_enabled() {
         let mut bridge = config::BridgeConfig::default();
@@ -825,6 +1303,97 @@ mod tests {
         assert!(kinds.contains(&KIND_LISTING));
     }
 
+    #[test]
+    fn settings_reload_plan_diffs_relays() {
+        let current = settings_with_relays(vec![
+            "wss://relay-a.example.com".to_string(),
+            "wss://relay-b.example.com".to_string(),
+        ]);
+        let next = settings_with_relays(vec![
+            "wss://relay-b.example.com".to_string(),
+            "wss://relay-c.example.com".to_string(),
+        ]);
+
+        let plan = settings_reload_plan(&current, &next);
+
+        assert_eq!(
+            plan,
+            SettingsReloadPlan {
+                added_relays: vec!["wss://relay-c.example.com".to_string()],
+                removed_relays: vec!["wss://relay-a.example.com".to_string()],
+                restart_required: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn settings_reload_plan_flags_sections_that_need_restart() {
+        let current = settings_with_relays(Vec::new());
+        let mut next = settings_with_relays(Vec::new());
+        next.config.rpc_addr = Some("127.0.0.1:9999".to_string());
+        next.config.bridge.enabled = true;
+
+        let plan = settings_reload_plan(&current, &next);
+
+        assert!(plan.added_relays.is_empty());
+        assert!(plan.removed_relays.is_empty());
+        assert_eq!(
+            plan.restart_required,
+            vec!["config.rpc", "config.bridge.enabled"]
+        );
+    }
+
+    #[test]
+    fn applied_settings_keeps_running_values_for_restart_required_changes() {
+        let current = settings_with_relays(vec!["wss://relay-a.example.com".to_string()]);
+        let mut next = settings_with_relays(vec!["wss://relay-a.example.com".to_string()]);
+        next.config.bridge.enabled = true;
+        next.config.rpc.shutdown_drain_secs += 1;
+
+        let applied = super::applied_settings(&current, &next);
+
+        assert!(!super::serialized_differs(&applied, &current));
+        assert_eq!(
+            settings_reload_plan(&applied, &next).restart_required,
+            vec!["config.rpc", "config.bridge.enabled"]
+        );
+    }
+
+    #[test]
+    fn applied_settings_takes_reloaded_relays() {
+        let current = settings_with_relays(vec!["wss://relay-a.example.com".to_string()]);
+        let mut next = settings_with_relays(vec!["wss://relay-b.example.com".to_string()]);
+        next.config.bridge.enabled = true;
+
+        let applied = super::applied_settings(&current, &next);
+
+        assert_eq!(
+            applied.config.service.relays,
+            vec!["wss://relay-b.example.com".to_string()]
+        );
+        assert!(!applied.config.bridge.enabled);
+    }
+
+    #[test]
+    fn settings_reload_plan_names_restart_required_bridge_and_nip46_fields() {
+        let current = settings_with_relays(Vec::new());
+        let mut next = settings_with_relays(Vec::new());
+        next.config.max_relays = Some(2);
+        next.config.bridge.kind_aliases.insert(30402, 30403);
+        next.config.nip46.session_ttl_secs += 1;
+
+        let plan = settings_reload_plan(&current, &next);
+
+        assert_eq!(
+            plan.restart_required,
+            vec![
+                "config.max_relays",
+                "config.bridge.kind_aliases",
+                "config.nip46.session_ttl_secs",
+            ]
+        );
+    }
+
     #[test]
     fn runtime_startup_report_prefers_explicit_cli_paths() {
         let args = cli::Args {
@@ -833,6 +1402,7 @@ mod tests {
                 identity: Some(PathBuf::from("/tmp/radrootsd/identity.secret.json")),
                 allow_generate_identity: false,
             },
+            config_overlays: Vec::new(),
         };
         let mut settings = settings_with_relays(Vec::new());
         settings.config.service.logs_dir = "/tmp/radrootsd/logs".to_string();
@@ -882,6 +1452,7 @@ mod tests {
                 identity: None,
                 allow_generate_identity: false,
             },
+            config_overlays: Vec::new(),
         };
         let contract = sample_runtime_contract();
         let mut settings = settings_with_relays(Vec::new());
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BridgeCoordinate {
+    pub kind: u32,
+    pub author: String,
+    pub d_tag: String,
+}
+
+impl BridgeCoordinate {
+    pub fn new(kind: u32, author: impl Into<String>, d_tag: impl Into<String>) -> Self {
+        Self {
+            kind,
+            author: author.into(),
+            d_tag: d_tag.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BridgeCoordinateLocks {
+    inner: Arc<Mutex<HashMap<BridgeCoordinate, Arc<AsyncMutex<()>>>>>,
+}
+
+impl BridgeCoordinateLocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn acquire(&self, coordinate: BridgeCoordinate) -> OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+            locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+            locks
+                .entry(coordinate)
+                .or_insert_with(|| Arc::new(AsyncMutex::new(())))
+                .clone()
+        };
+        lock.lock_owned().await
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::sync::Mutex;
+
+    use super::{BridgeCoordinate, BridgeCoordinateLocks};
+
+    #[tokio::test]
+    async fn concurrent_follow_adds_on_same_coordinate_do_not_clobber() {
+        let locks = BridgeCoordinateLocks::new();
+        let follows = Arc::new(Mutex::new(Vec::<String>::new()));
+
+        let add_follow = |pubkey: &'static str| {
+            let locks = locks.clone();
+            let follows = follows.clone();
+            async move {
+                let _guard = locks.acquire(BridgeCoordinate::new(3, "author", "")).await;
+                let mut next = follows.lock().await.clone();
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                next.push(pubkey.to_string());
+                *follows.lock().await = next;
+            }
+        };
+
+        tokio::join!(add_follow("alice"), add_follow("bob"));
+
+        let mut follows = follows.lock().await.clone();
+        follows.sort();
+        assert_eq!(follows, vec!["alice".to_string(), "bob".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn distinct_coordinates_do_not_block_each_other() {
+        let locks = BridgeCoordinateLocks::new();
+        let _first = locks
+            .acquire(BridgeCoordinate::new(30402, "author", "listing-a"))
+            .await;
+        let second = tokio::time::timeout(
+            Duration::from_millis(50),
+            locks.acquire(BridgeCoordinate::new(30402, "author", "listing-b")),
+        )
+        .await;
+        assert!(second.is_ok());
+    }
+
+    #[tokio::test]
+    async fn released_coordinates_are_pruned() {
+        let locks = BridgeCoordinateLocks::new();
+        drop(
+            locks
+                .acquire(BridgeCoordinate::new(30340, "author", "farm"))
+                .await,
+        );
+        let _held = locks
+            .acquire(BridgeCoordinate::new(30402, "author", "listing"))
+            .await;
+        assert_eq!(locks.len(), 1);
+    }
+}
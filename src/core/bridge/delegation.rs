@@ -0,0 +1,212 @@
+use std::str::FromStr;
+
+use nostr::secp256k1::schnorr::Signature;
+use nostr::secp256k1::{Message, Secp256k1, XOnlyPublicKey};
+use nostr::{Event, PublicKey, Tag};
+use sha2::{Digest, Sha256};
+
+const DELEGATION_TAG: &str = "delegation";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DelegationConditions {
+    pub kinds: Vec<u16>,
+    pub created_after: Option<u64>,
+    pub created_before: Option<u64>,
+}
+
+impl DelegationConditions {
+    pub fn parse(conditions: &str) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        for clause in conditions.split('&') {
+            if let Some(kind) = clause.strip_prefix("kind=") {
+                let kind = kind
+                    .parse()
+                    .map_err(|_| format!("invalid delegation kind `{kind}`"))?;
+                parsed.kinds.push(kind);
+            } else if let Some(after) = clause.strip_prefix("created_at>") {
+                parsed.created_after = Some(parse_timestamp(after)?);
+            } else if let Some(before) = clause.strip_prefix("created_at<") {
+                parsed.created_before = Some(parse_timestamp(before)?);
+            } else {
+                return Err(format!("unsupported delegation condition `{clause}`"));
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn check(&self, kind: u16, created_at: u64) -> Result<(), String> {
+        if !self.kinds.is_empty() && !self.kinds.contains(&kind) {
+            return Err(format!("delegation does not allow kind {kind}"));
+        }
+        if let Some(after) = self.created_after
+            && created_at <= after
+        {
+            return Err(format!("delegation requires created_at > {after}"));
+        }
+        if let Some(before) = self.created_before
+            && created_at >= before
+        {
+            return Err(format!("delegation requires created_at < {before}"));
+        }
+        Ok(())
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid delegation timestamp `{value}`"))
+}
+
+pub fn delegation_message(delegatee: &PublicKey, conditions: &str) -> Message {
+    let digest = Sha256::digest(format!(
+        "nostr:delegation:{}:{conditions}",
+        delegatee.to_hex()
+    ));
+    Message::from_digest(digest.into())
+}
+
+pub fn verify_delegation_token(
+    delegator: &PublicKey,
+    delegatee: &PublicKey,
+    conditions: &str,
+    token: &str,
+) -> Result<(), String> {
+    let signature =
+        Signature::from_str(token).map_err(|error| format!("invalid delegation token: {error}"))?;
+    let delegator = XOnlyPublicKey::from_slice(&delegator.to_bytes())
+        .map_err(|error| format!("invalid delegator pubkey: {error}"))?;
+    Secp256k1::verification_only()
+        .verify_schnorr(
+            &signature,
+            &delegation_message(delegatee, conditions),
+            &delegator,
+        )
+        .map_err(|_| "delegation token was not signed by the delegator".to_string())
+}
+
+pub fn delegation_tag(delegator: &PublicKey, conditions: &str, token: &str) -> Result<Tag, String> {
+    Tag::parse([DELEGATION_TAG, &delegator.to_hex(), conditions, token])
+        .map_err(|error| format!("invalid delegation tag: {error}"))
+}
+
+pub fn validate_delegated_event(event: &Event) -> Result<(), String> {
+    let Some(tag) = event
+        .tags
+        .iter()
+        .find(|tag| tag.as_slice().first().map(String::as_str) == Some(DELEGATION_TAG))
+    else {
+        return Ok(());
+    };
+    let [_, delegator, conditions, token] = tag.as_slice() else {
+        return Err("delegation tag must carry delegator, conditions and token".to_string());
+    };
+    let delegator = PublicKey::from_hex(delegator)
+        .map_err(|error| format!("invalid delegator pubkey: {error}"))?;
+    verify_delegation_token(&delegator, &event.pubkey, conditions, token)?;
+    DelegationConditions::parse(conditions)?.check(event.kind.as_u16(), event.created_at.as_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use radroots_nostr::prelude::{
+        RadrootsNostrEventBuilder, RadrootsNostrKeys, RadrootsNostrTimestamp,
+    };
+
+    use super::{
+        DelegationConditions, delegation_message, delegation_tag, validate_delegated_event,
+        verify_delegation_token,
+    };
+
+    fn token(
+        delegator: &RadrootsNostrKeys,
+        delegatee: &RadrootsNostrKeys,
+        conditions: &str,
+    ) -> String {
+        delegator
+            .sign_schnorr(&delegation_message(&delegatee.public_key(), conditions))
+            .to_string()
+    }
+
+    #[test]
+    fn delegation_conditions_check_kind_and_time_window() {
+        let conditions =
+            DelegationConditions::parse("kind=1&kind=30402&created_at>1000&created_at<2000")
+                .expect("conditions");
+        assert_eq!(conditions.kinds, vec![1, 30402]);
+
+        assert!(conditions.check(30402, 1500).is_ok());
+        assert!(conditions.check(0, 1500).is_err());
+        assert!(conditions.check(1, 1000).is_err());
+        assert!(conditions.check(1, 2000).is_err());
+        assert!(DelegationConditions::parse("pubkey=abc").is_err());
+    }
+
+    #[test]
+    fn delegation_token_must_be_signed_by_delegator() {
+        let delegator = RadrootsNostrKeys::generate();
+        let delegatee = RadrootsNostrKeys::generate();
+        let conditions = "kind=1";
+        let signed = token(&delegator, &delegatee, conditions);
+
+        assert!(
+            verify_delegation_token(
+                &delegator.public_key(),
+                &delegatee.public_key(),
+                conditions,
+                &signed,
+            )
+            .is_ok()
+        );
+        assert!(
+            verify_delegation_token(
+                &delegatee.public_key(),
+                &delegatee.public_key(),
+                conditions,
+                &signed,
+            )
+            .is_err()
+        );
+        assert!(
+            verify_delegation_token(
+                &delegator.public_key(),
+                &delegatee.public_key(),
+                "kind=0",
+                &signed,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn delegated_event_carries_tag_and_validates() {
+        let delegator = RadrootsNostrKeys::generate();
+        let delegatee = RadrootsNostrKeys::generate();
+        let conditions = "kind=1&created_at>1000";
+        let signed = token(&delegator, &delegatee, conditions);
+        let tag = delegation_tag(&delegator.public_key(), conditions, &signed).expect("tag");
+        assert_eq!(
+            tag.as_slice(),
+            [
+                "delegation".to_string(),
+                delegator.public_key().to_hex(),
+                conditions.to_string(),
+                signed.clone(),
+            ]
+        );
+
+        let event = RadrootsNostrEventBuilder::text_note("eggs")
+            .tag(tag.clone())
+            .custom_created_at(RadrootsNostrTimestamp::from(1_500))
+            .sign_with_keys(&delegatee)
+            .expect("event");
+        assert!(validate_delegated_event(&event).is_ok());
+
+        let early = RadrootsNostrEventBuilder::text_note("eggs")
+            .tag(tag)
+            .custom_created_at(RadrootsNostrTimestamp::from(900))
+            .sign_with_keys(&delegatee)
+            .expect("event");
+        assert!(validate_delegated_event(&early).is_err());
+    }
+}
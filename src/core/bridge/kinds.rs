@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use radroots_events::kinds::{
+    KIND_FARM, KIND_LISTING, KIND_LISTING_DRAFT, KIND_PROFILE, KIND_TRADE_ORDER_REQUEST,
+};
+use radroots_events::trade::RadrootsTradeMessageType as TradeListingMessageType;
+use serde::Serialize;
+
+const KIND_TEXT_NOTE: u32 = 1;
+
+const EVENT_KIND_LABELS: &[(u32, &str)] = &[
+    (KIND_PROFILE, "profile"),
+    (KIND_TEXT_NOTE, "text_note"),
+    (KIND_FARM, "farm"),
+    (KIND_LISTING, "listing"),
+    (KIND_LISTING_DRAFT, "listing_draft"),
+    (KIND_TRADE_ORDER_REQUEST, "order_request"),
+];
+
+const TRADE_MESSAGE_LABELS: &[(TradeListingMessageType, &str)] = &[
+    (TradeListingMessageType::OrderResponse, "order_response"),
+    (TradeListingMessageType::OrderRevision, "order_revision"),
+    (
+        TradeListingMessageType::OrderRevisionAccept,
+        "order_revision_accept",
+    ),
+    (
+        TradeListingMessageType::OrderRevisionDecline,
+        "order_revision_decline",
+    ),
+    (TradeListingMessageType::Question, "order_question"),
+    (TradeListingMessageType::Answer, "order_answer"),
+    (
+        TradeListingMessageType::DiscountRequest,
+        "order_discount_request",
+    ),
+    (
+        TradeListingMessageType::DiscountOffer,
+        "order_discount_offer",
+    ),
+    (
+        TradeListingMessageType::DiscountAccept,
+        "order_discount_accept",
+    ),
+    (
+        TradeListingMessageType::DiscountDecline,
+        "order_discount_decline",
+    ),
+    (TradeListingMessageType::Cancel, "order_cancel"),
+    (
+        TradeListingMessageType::FulfillmentUpdate,
+        "order_fulfillment_update",
+    ),
+    (TradeListingMessageType::Receipt, "order_receipt"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KindClass {
+    Regular,
+    Replaceable,
+    Ephemeral,
+    Addressable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct KindCatalogEntry {
+    pub kind: u32,
+    pub label: &'static str,
+    pub class: KindClass,
+}
+
+pub fn kind_class(kind: u32) -> KindClass {
+    match kind {
+        0 | 3 | 10_000..20_000 => KindClass::Replaceable,
+        20_000..30_000 => KindClass::Ephemeral,
+        30_000..40_000 => KindClass::Addressable,
+        _ => KindClass::Regular,
+    }
+}
+
+fn kind_labels() -> impl Iterator<Item = (u32, &'static str)> {
+    EVENT_KIND_LABELS.iter().copied().chain(
+        TRADE_MESSAGE_LABELS
+            .iter()
+            .map(|&(message_type, label)| (message_type.kind(), label)),
+    )
+}
+
+pub fn kind_catalog() -> Vec<KindCatalogEntry> {
+    let mut catalog = Vec::<KindCatalogEntry>::new();
+    for (kind, label) in kind_labels() {
+        if catalog.iter().any(|entry| entry.kind == kind) {
+            continue;
+        }
+        catalog.push(KindCatalogEntry {
+            kind,
+            label,
+            class: kind_class(kind),
+        });
+    }
+    catalog
+}
+
+pub fn kind_label(kind: u32) -> Option<&'static str> {
+    kind_labels()
+        .find(|(known, _)| *known == kind)
+        .map(|(_, label)| label)
+}
+
+pub fn canonical_kind(kind: u32, aliases: &HashMap<u32, u32>) -> u32 {
+    aliases.get(&kind).copied().unwrap_or(kind)
+}
+
+pub fn aliased_kinds(kind: u32, aliases: &HashMap<u32, u32>) -> Vec<u32> {
+    let mut kinds = aliases
+        .iter()
+        .filter(|(_, current)| **current == kind)
+        .map(|(old, _)| *old)
+        .collect::<Vec<_>>();
+    kinds.sort_unstable();
+    kinds
+}
+
+#[cfg(test)]
+mod tests {
+    use radroots_events::kinds::{KIND_FARM, KIND_LISTING, KIND_LISTING_DRAFT};
+    use radroots_events::trade::RadrootsTradeMessageType as TradeListingMessageType;
+
+    use std::collections::HashMap;
+
+    use super::{KindClass, aliased_kinds, canonical_kind, kind_catalog, kind_class, kind_label};
+
+    #[test]
+    fn kind_label_resolves_known_kinds() {
+        assert_eq!(kind_label(KIND_LISTING), Some("listing"));
+        assert_eq!(kind_label(30402), Some("listing"));
+        assert_eq!(kind_label(KIND_LISTING_DRAFT), Some("listing_draft"));
+        assert_eq!(kind_label(KIND_FARM), Some("farm"));
+        assert_eq!(kind_label(1), Some("text_note"));
+    }
+
+    #[test]
+    fn legacy_kind_aliases_resolve_to_current_kind() {
+        let aliases = HashMap::from([(30_400, KIND_LISTING), (30_401, KIND_LISTING)]);
+
+        assert_eq!(aliased_kinds(KIND_LISTING, &aliases), vec![30_400, 30_401]);
+        assert!(aliased_kinds(KIND_FARM, &aliases).is_empty());
+        assert_eq!(
+            kind_label(canonical_kind(30_400, &aliases)),
+            Some("listing")
+        );
+        assert_eq!(canonical_kind(KIND_FARM, &aliases), KIND_FARM);
+    }
+
+    #[test]
+    fn kind_catalog_lists_published_trade_message_kinds_once() {
+        let catalog = kind_catalog();
+        for message_type in [
+            TradeListingMessageType::OrderResponse,
+            TradeListingMessageType::DiscountOffer,
+            TradeListingMessageType::Receipt,
+        ] {
+            assert!(
+                catalog
+                    .iter()
+                    .any(|entry| entry.kind == message_type.kind())
+            );
+        }
+        let mut kinds = catalog.iter().map(|entry| entry.kind).collect::<Vec<_>>();
+        kinds.sort_unstable();
+        kinds.dedup();
+        assert_eq!(kinds.len(), catalog.len());
+    }
+
+    #[test]
+    fn kind_label_is_none_for_unknown_kinds() {
+        assert_eq!(kind_label(65_000), None);
+    }
+
+    #[test]
+    fn kind_class_follows_nip01_ranges() {
+        assert_eq!(kind_class(0), KindClass::Replaceable);
+        assert_eq!(kind_class(1), KindClass::Regular);
+        assert_eq!(kind_class(10_002), KindClass::Replaceable);
+        assert_eq!(kind_class(24_133), KindClass::Ephemeral);
+        assert_eq!(kind_class(30_402), KindClass::Addressable);
+        assert_eq!(kind_class(40_000), KindClass::Regular);
+    }
+
+    #[test]
+    fn kind_catalog_includes_listing_as_addressable() {
+        let listing = kind_catalog()
+            .into_iter()
+            .find(|entry| entry.kind == KIND_LISTING)
+            .expect("listing entry");
+        assert_eq!(listing.label, "listing");
+        assert_eq!(listing.class, KindClass::Addressable);
+    }
+}
@@ -1,2 +1,8 @@
+pub mod coordinate;
+pub mod delegation;
+pub mod kinds;
+pub mod order_lifecycle;
 pub mod publish;
+pub mod relay_errors;
+pub mod relay_stats;
 pub mod store;
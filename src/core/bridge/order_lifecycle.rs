@@ -0,0 +1,282 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeOrderStep {
+    Request,
+    Response,
+    Revision,
+    RevisionAccept,
+    RevisionDecline,
+    Question,
+    Answer,
+    DiscountRequest,
+    DiscountOffer,
+    DiscountAccept,
+    DiscountDecline,
+    Cancel,
+    FulfillmentUpdate,
+    Receipt,
+}
+
+impl BridgeOrderStep {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Request => "request",
+            Self::Response => "response",
+            Self::Revision => "revision",
+            Self::RevisionAccept => "revision_accept",
+            Self::RevisionDecline => "revision_decline",
+            Self::Question => "question",
+            Self::Answer => "answer",
+            Self::DiscountRequest => "discount_request",
+            Self::DiscountOffer => "discount_offer",
+            Self::DiscountAccept => "discount_accept",
+            Self::DiscountDecline => "discount_decline",
+            Self::Cancel => "cancel",
+            Self::FulfillmentUpdate => "fulfillment_update",
+            Self::Receipt => "receipt",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct BridgeOrderLifecycle {
+    requested: bool,
+    responded: bool,
+    pending_revision: bool,
+    open_questions: usize,
+    pending_discount_offer: bool,
+    fulfillment_started: bool,
+    closed_by: Option<BridgeOrderStep>,
+}
+
+impl BridgeOrderLifecycle {
+    fn apply(&mut self, step: BridgeOrderStep) -> Result<(), String> {
+        if let Some(closed_by) = self.closed_by {
+            return Err(format!(
+                "order is closed by {}; {} is not allowed",
+                closed_by.as_str(),
+                step.as_str()
+            ));
+        }
+        if step != BridgeOrderStep::Request && !self.requested {
+            return Err(format!("{} requires a prior request", step.as_str()));
+        }
+        match step {
+            BridgeOrderStep::Request => {
+                if self.requested {
+                    return Err("order has already been requested".to_string());
+                }
+                self.requested = true;
+            }
+            BridgeOrderStep::Response => {
+                if self.responded {
+                    return Err("order has already been responded to".to_string());
+                }
+                self.responded = true;
+            }
+            BridgeOrderStep::Revision => {
+                if self.pending_revision {
+                    return Err("a revision is already awaiting a decision".to_string());
+                }
+                self.pending_revision = true;
+            }
+            BridgeOrderStep::RevisionAccept | BridgeOrderStep::RevisionDecline => {
+                if !self.pending_revision {
+                    return Err(format!("{} requires a pending revision", step.as_str()));
+                }
+                self.pending_revision = false;
+            }
+            BridgeOrderStep::Question => {
+                self.open_questions += 1;
+            }
+            BridgeOrderStep::Answer => {
+                if self.open_questions == 0 {
+                    return Err("answer requires an open question".to_string());
+                }
+                self.open_questions -= 1;
+            }
+            BridgeOrderStep::DiscountRequest => {}
+            BridgeOrderStep::DiscountOffer => {
+                self.pending_discount_offer = true;
+            }
+            BridgeOrderStep::DiscountAccept | BridgeOrderStep::DiscountDecline => {
+                if !self.pending_discount_offer {
+                    return Err(format!(
+                        "{} requires a pending discount offer",
+                        step.as_str()
+                    ));
+                }
+                self.pending_discount_offer = false;
+            }
+            BridgeOrderStep::FulfillmentUpdate => {
+                if !self.responded {
+                    return Err("fulfillment_update requires a response".to_string());
+                }
+                if self.pending_revision {
+                    return Err(
+                        "fulfillment_update is not allowed while a revision is pending".to_string(),
+                    );
+                }
+                self.fulfillment_started = true;
+            }
+            BridgeOrderStep::Receipt => {
+                if !self.fulfillment_started {
+                    return Err("receipt requires a fulfillment_update".to_string());
+                }
+                self.closed_by = Some(step);
+            }
+            BridgeOrderStep::Cancel => {
+                self.closed_by = Some(step);
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn validate_order_transition(
+    history: &[BridgeOrderStep],
+    next: BridgeOrderStep,
+) -> Result<(), String> {
+    let mut lifecycle = BridgeOrderLifecycle::default();
+    for (index, step) in history.iter().enumerate() {
+        lifecycle
+            .apply(*step)
+            .map_err(|reason| format!("history[{index}] is invalid: {reason}"))?;
+    }
+    lifecycle.apply(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BridgeOrderStep::{self, *};
+    use super::validate_order_transition;
+
+    const ALL_STEPS: [BridgeOrderStep; 14] = [
+        Request,
+        Response,
+        Revision,
+        RevisionAccept,
+        RevisionDecline,
+        Question,
+        Answer,
+        DiscountRequest,
+        DiscountOffer,
+        DiscountAccept,
+        DiscountDecline,
+        Cancel,
+        FulfillmentUpdate,
+        Receipt,
+    ];
+
+    fn allowed_after(history: &[BridgeOrderStep]) -> Vec<BridgeOrderStep> {
+        ALL_STEPS
+            .into_iter()
+            .filter(|next| validate_order_transition(history, *next).is_ok())
+            .collect()
+    }
+
+    #[test]
+    fn empty_history_only_allows_request() {
+        assert_eq!(allowed_after(&[]), vec![Request]);
+    }
+
+    #[test]
+    fn requested_order_allows_negotiation_but_not_fulfillment() {
+        assert_eq!(
+            allowed_after(&[Request]),
+            vec![
+                Response,
+                Revision,
+                Question,
+                DiscountRequest,
+                DiscountOffer,
+                Cancel
+            ]
+        );
+    }
+
+    #[test]
+    fn responded_order_allows_fulfillment() {
+        assert_eq!(
+            allowed_after(&[Request, Response]),
+            vec![
+                Revision,
+                Question,
+                DiscountRequest,
+                DiscountOffer,
+                Cancel,
+                FulfillmentUpdate
+            ]
+        );
+    }
+
+    #[test]
+    fn pending_revision_requires_decision_before_fulfillment() {
+        assert_eq!(
+            allowed_after(&[Request, Response, Revision]),
+            vec![
+                RevisionAccept,
+                RevisionDecline,
+                Question,
+                DiscountRequest,
+                DiscountOffer,
+                Cancel
+            ]
+        );
+        assert!(
+            validate_order_transition(
+                &[Request, Response, Revision, RevisionAccept],
+                FulfillmentUpdate
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn answers_track_open_questions() {
+        assert!(validate_order_transition(&[Request, Question], Answer).is_ok());
+        let reason = validate_order_transition(&[Request, Question, Answer], Answer).unwrap_err();
+        assert!(reason.contains("open question"));
+    }
+
+    #[test]
+    fn discount_decisions_require_offer() {
+        assert!(validate_order_transition(&[Request, DiscountRequest], DiscountAccept).is_err());
+        assert!(
+            validate_order_transition(&[Request, DiscountRequest, DiscountOffer], DiscountDecline)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn receipt_requires_fulfillment_and_closes_order() {
+        let reason = validate_order_transition(&[Request, Response], Receipt).unwrap_err();
+        assert!(reason.contains("fulfillment_update"));
+        let fulfilled = [Request, Response, FulfillmentUpdate];
+        assert!(allowed_after(&fulfilled).contains(&Receipt));
+        let closed = [Request, Response, FulfillmentUpdate, Receipt];
+        assert!(allowed_after(&closed).is_empty());
+    }
+
+    #[test]
+    fn cancel_closes_order() {
+        assert!(allowed_after(&[Request, Cancel]).is_empty());
+        let reason = validate_order_transition(&[Request, Cancel], Response).unwrap_err();
+        assert!(reason.contains("closed by cancel"));
+    }
+
+    #[test]
+    fn invalid_history_is_reported_with_index() {
+        let reason = validate_order_transition(&[Request, Receipt], Cancel).unwrap_err();
+        assert!(reason.starts_with("history[1] is invalid"));
+    }
+
+    #[test]
+    fn steps_deserialize_from_snake_case() {
+        let step: BridgeOrderStep = serde_json::from_str("\"fulfillment_update\"").expect("step");
+        assert_eq!(step, FulfillmentUpdate);
+        assert_eq!(step.as_str(), "fulfillment_update");
+    }
+}
@@ -1,11 +1,14 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
 use std::time::Duration;
 
 use radroots_nostr::prelude::{RadrootsNostrClient, RadrootsNostrOutput, RadrootsNostrRelayUrl};
 use serde::{Deserialize, Serialize};
-use tokio::time::sleep;
+use tokio::task::JoinSet;
+use tokio::time::{Instant, sleep, timeout};
 
 use crate::app::config::{BridgeConfig, BridgeDeliveryPolicy};
+use crate::core::bridge::relay_stats::BridgeRelayStats;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BridgeRelayPublishResult {
@@ -36,6 +39,7 @@ pub struct BridgePublishSettings {
     pub publish_max_attempts: usize,
     pub publish_initial_backoff_millis: u64,
     pub publish_max_backoff_millis: u64,
+    pub publish_reconnect_failed: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -55,6 +59,7 @@ impl BridgePublishSettings {
             publish_max_attempts: config.publish_max_attempts,
             publish_initial_backoff_millis: config.publish_initial_backoff_millis,
             publish_max_backoff_millis: config.publish_max_backoff_millis,
+            publish_reconnect_failed: config.publish_reconnect_failed,
         }
     }
 
@@ -115,6 +120,7 @@ impl BridgePublishSettings {
 
 pub async fn connect_and_publish_event(
     client: &RadrootsNostrClient,
+    relay_stats: &BridgeRelayStats,
     settings: &BridgePublishSettings,
     event: &radroots_nostr::prelude::RadrootsNostrEvent,
 ) -> BridgePublishExecution {
@@ -124,19 +130,105 @@ pub async fn connect_and_publish_event(
         .keys()
         .cloned()
         .collect::<Vec<RadrootsNostrRelayUrl>>();
-    publish_with_policy(&relays, settings, || async {
+    let reconnect_deadline = Instant::now() + Duration::from_secs(settings.connect_timeout_secs);
+    let execution = publish_with_policy(&relays, settings, || async {
         client.connect().await;
         client
             .wait_for_connection(Duration::from_secs(settings.connect_timeout_secs))
             .await;
-        client
+        let output = client
             .send_event(event)
             .await
-            .map_err(|error| error.to_string())
+            .map_err(|error| error.to_string())?;
+        if !settings.publish_reconnect_failed {
+            return Ok(output);
+        }
+        Ok(reconnect_and_retry_failed(client, event, output, reconnect_deadline).await)
     })
+    .await;
+    relay_stats.record(&execution.relay_results);
+    execution
+}
+
+async fn reconnect_and_retry_failed<T>(
+    client: &RadrootsNostrClient,
+    event: &radroots_nostr::prelude::RadrootsNostrEvent,
+    output: RadrootsNostrOutput<T>,
+    deadline: Instant,
+) -> RadrootsNostrOutput<T> {
+    let pool = client.relays().await;
+    let disconnected = output
+        .failed
+        .keys()
+        .filter(|relay| pool.get(*relay).is_some_and(|relay| !relay.is_connected()))
+        .cloned()
+        .collect::<Vec<_>>();
+    let budget = deadline.saturating_duration_since(Instant::now());
+    retry_disconnected(
+        output,
+        disconnected,
+        budget,
+        |relay| {
+            let client = client.clone();
+            async move {
+                let _ = client.try_connect_relay(relay, budget).await;
+            }
+        },
+        |relays| async move {
+            client
+                .send_event_to(relays, event)
+                .await
+                .map_err(|error| error.to_string())
+        },
+    )
     .await
 }
 
+async fn retry_disconnected<T, U, C, CFut, R, RFut>(
+    output: RadrootsNostrOutput<T>,
+    disconnected: Vec<RadrootsNostrRelayUrl>,
+    budget: Duration,
+    connect: C,
+    resend: R,
+) -> RadrootsNostrOutput<T>
+where
+    C: Fn(RadrootsNostrRelayUrl) -> CFut,
+    CFut: Future<Output = ()> + Send + 'static,
+    R: FnOnce(Vec<RadrootsNostrRelayUrl>) -> RFut,
+    RFut: Future<Output = Result<RadrootsNostrOutput<U>, String>>,
+{
+    if disconnected.is_empty() || budget.is_zero() {
+        return output;
+    }
+    let mut connects = JoinSet::new();
+    for relay in &disconnected {
+        connects.spawn(connect(relay.clone()));
+    }
+    let _ = timeout(budget, async {
+        while connects.join_next().await.is_some() {}
+    })
+    .await;
+    drop(connects);
+    match resend(disconnected).await {
+        Ok(retry) => merge_reconnect_retry(output, retry),
+        Err(_) => output,
+    }
+}
+
+fn merge_reconnect_retry<T, U>(
+    mut output: RadrootsNostrOutput<T>,
+    retry: RadrootsNostrOutput<U>,
+) -> RadrootsNostrOutput<T> {
+    for relay in retry.success {
+        output.failed.remove(&relay);
+        output.success.insert(relay);
+    }
+    for (relay, error) in retry.failed {
+        output.failed.insert(relay, error);
+    }
+    output
+}
+
 pub fn failed_prepublish_execution(
     settings: &BridgePublishSettings,
     summary: impl Into<String>,
@@ -413,7 +505,9 @@ fn summarize_delivery_policy_result(
 #[cfg(test)]
 mod tests {
     use std::collections::{HashMap, HashSet};
+    use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     use radroots_nostr::prelude::{
         RadrootsNostrEventId, RadrootsNostrOutput, RadrootsNostrRelayUrl,
@@ -422,7 +516,9 @@ mod tests {
 
     use crate::app::config::{BridgeConfig, BridgeDeliveryPolicy};
 
-    use super::{BridgePublishSettings, publish_with_policy};
+    use super::{
+        BridgePublishSettings, merge_reconnect_retry, publish_with_policy, retry_disconnected,
+    };
 
     #[test]
     fn publish_settings_from_config_copies_values() {
@@ -448,6 +544,7 @@ mod tests {
                 publish_max_attempts: 3,
                 publish_initial_backoff_millis: 125,
                 publish_max_backoff_millis: 500,
+                publish_reconnect_failed: true,
             }
         );
     }
@@ -465,6 +562,7 @@ mod tests {
             publish_max_attempts: 2,
             publish_initial_backoff_millis: 10,
             publish_max_backoff_millis: 10,
+            publish_reconnect_failed: true,
         };
         let attempts = Arc::new(Mutex::new(vec![
             publish_output(
@@ -518,6 +616,7 @@ mod tests {
             publish_max_attempts: 2,
             publish_initial_backoff_millis: 1,
             publish_max_backoff_millis: 1,
+            publish_reconnect_failed: true,
         };
 
         let outcome =
@@ -550,6 +649,7 @@ mod tests {
             publish_max_attempts: 1,
             publish_initial_backoff_millis: 10,
             publish_max_backoff_millis: 10,
+            publish_reconnect_failed: true,
         };
 
         let outcome = publish_with_policy::<RadrootsNostrEventId, _, _>(&[], &settings, || async {
@@ -562,6 +662,127 @@ mod tests {
         assert!(outcome.relay_outcome_summary.contains("cannot publish"));
     }
 
+    #[test]
+    fn merge_reconnect_retry_moves_reconnected_relay_to_success() {
+        let first = publish_output(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            &["wss://relay-a.example.com"],
+            &[
+                ("wss://relay-b.example.com", "relay not connected"),
+                ("wss://relay-c.example.com", "relay not connected"),
+            ],
+        );
+        let retry = publish_output(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            &["wss://relay-b.example.com"],
+            &[("wss://relay-c.example.com", "connection refused")],
+        );
+
+        let merged = merge_reconnect_retry(first, retry);
+
+        let relay_b = RadrootsNostrRelayUrl::parse("wss://relay-b.example.com").expect("relay-b");
+        let relay_c = RadrootsNostrRelayUrl::parse("wss://relay-c.example.com").expect("relay-c");
+        assert_eq!(merged.success.len(), 2);
+        assert!(merged.success.contains(&relay_b));
+        assert!(!merged.failed.contains_key(&relay_b));
+        assert_eq!(
+            merged.failed.get(&relay_c).map(String::as_str),
+            Some("connection refused")
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_with_policy_sends_to_reconnected_relay_in_same_attempt() {
+        let relays = vec![
+            RadrootsNostrRelayUrl::parse("wss://relay-a.example.com").expect("relay-a"),
+            RadrootsNostrRelayUrl::parse("wss://relay-b.example.com").expect("relay-b"),
+        ];
+        let relay_b = relays[1].clone();
+        let settings = BridgePublishSettings {
+            connect_timeout_secs: 10,
+            delivery_policy: BridgeDeliveryPolicy::All,
+            delivery_quorum: None,
+            publish_max_attempts: 1,
+            publish_initial_backoff_millis: 10,
+            publish_max_backoff_millis: 10,
+            publish_reconnect_failed: true,
+        };
+        let connected = Arc::new(AtomicBool::new(false));
+
+        let outcome = publish_with_policy(&relays, &settings, || {
+            let connected = Arc::clone(&connected);
+            let relay_b = relay_b.clone();
+            async move {
+                let output = publish_output(
+                    "0000000000000000000000000000000000000000000000000000000000000002",
+                    &["wss://relay-a.example.com"],
+                    &[("wss://relay-b.example.com", "relay not connected")],
+                );
+                let reconnect = Arc::clone(&connected);
+                Ok(retry_disconnected(
+                    output,
+                    vec![relay_b],
+                    Duration::from_secs(1),
+                    move |_| {
+                        let reconnect = Arc::clone(&reconnect);
+                        async move { reconnect.store(true, Ordering::SeqCst) }
+                    },
+                    |_| async move {
+                        assert!(connected.load(Ordering::SeqCst));
+                        Ok(publish_output(
+                            "0000000000000000000000000000000000000000000000000000000000000002",
+                            &["wss://relay-b.example.com"],
+                            &[],
+                        ))
+                    },
+                )
+                .await)
+            }
+        })
+        .await;
+
+        assert!(outcome.published);
+        assert_eq!(outcome.attempt_count, 1);
+        assert_eq!(outcome.acknowledged_relay_count, 2);
+        assert!(
+            outcome
+                .relay_results
+                .iter()
+                .all(|result| result.acknowledged)
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_disconnected_connects_concurrently_within_one_budget() {
+        let disconnected = ["wss://relay-b.example.com", "wss://relay-c.example.com"]
+            .iter()
+            .map(|relay| RadrootsNostrRelayUrl::parse(*relay).expect("relay"))
+            .collect::<Vec<_>>();
+        let output = publish_output(
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            &[],
+            &[
+                ("wss://relay-b.example.com", "relay not connected"),
+                ("wss://relay-c.example.com", "relay not connected"),
+            ],
+        );
+
+        let start = Instant::now();
+        let merged = retry_disconnected(
+            output,
+            disconnected,
+            Duration::from_millis(50),
+            |_| tokio::time::sleep(Duration::from_secs(60)),
+            |_| async {
+                Err::<RadrootsNostrOutput<RadrootsNostrEventId>, _>("offline".to_string())
+            },
+        )
+        .await;
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(merged.failed.len(), 2);
+    }
+
     fn publish_output(
         event_id_hex: &str,
         succeeded_relays: &[&str],
@@ -0,0 +1,99 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+use crate::core::bridge::store::unix_timestamp_now;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BridgeRelayError {
+    pub at_unix: u64,
+    pub kind: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BridgeRelayErrorLog {
+    inner: Arc<Mutex<BTreeMap<String, VecDeque<BridgeRelayError>>>>,
+    capacity: usize,
+}
+
+impl BridgeRelayErrorLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::default(),
+            capacity,
+        }
+    }
+
+    pub fn push(&self, relay_url: &str, kind: &'static str, message: String) {
+        self.push_at(relay_url, kind, message, unix_timestamp_now());
+    }
+
+    fn push_at(&self, relay_url: &str, kind: &'static str, message: String, at_unix: u64) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let history = inner.entry(relay_url.to_string()).or_default();
+        while history.len() >= self.capacity {
+            history.pop_front();
+        }
+        history.push_back(BridgeRelayError {
+            at_unix,
+            kind,
+            message,
+        });
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<String, Vec<BridgeRelayError>> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(relay_url, history)| (relay_url.clone(), history.iter().cloned().collect()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BridgeRelayErrorLog;
+
+    #[test]
+    fn push_retains_only_most_recent_errors() {
+        let log = BridgeRelayErrorLog::new(3);
+        for index in 0..5 {
+            log.push_at(
+                "wss://relay-a.example.com",
+                "notice",
+                format!("error {index}"),
+                index,
+            );
+        }
+        log.push(
+            "wss://relay-b.example.com",
+            "closed",
+            "auth-required".into(),
+        );
+
+        let snapshot = log.snapshot();
+        let relay_a = &snapshot["wss://relay-a.example.com"];
+        assert_eq!(
+            relay_a
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>(),
+            ["error 2", "error 3", "error 4"]
+        );
+        assert_eq!(relay_a[0].at_unix, 2);
+        assert_eq!(snapshot["wss://relay-b.example.com"].len(), 1);
+    }
+
+    #[test]
+    fn zero_capacity_disables_history() {
+        let log = BridgeRelayErrorLog::new(0);
+        log.push("wss://relay-a.example.com", "notice", "slow down".into());
+        assert!(log.snapshot().is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use nostr::JsonUtil;
+use radroots_nostr::prelude::RadrootsNostrEvent;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::core::bridge::publish::BridgeRelayPublishResult;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BridgeRelayPublishCounts {
+    pub accepted: u64,
+    pub rejected: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_rejection: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BridgeRelayStatsSnapshot {
+    pub relays: BTreeMap<String, BridgeRelayPublishCounts>,
+    pub oversized_events_dropped: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BridgeRelayStats {
+    inner: Arc<Mutex<BridgeRelayStatsSnapshot>>,
+}
+
+impl BridgeRelayStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, results: &[BridgeRelayPublishResult]) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        for result in results {
+            let counts = inner.relays.entry(result.relay_url.clone()).or_default();
+            if result.acknowledged {
+                counts.accepted += 1;
+            } else {
+                counts.rejected += 1;
+                if result.detail.is_some() {
+                    counts.last_rejection = result.detail.clone();
+                }
+            }
+        }
+    }
+
+    pub fn admit_event(&self, event: &RadrootsNostrEvent, max_event_bytes: usize) -> bool {
+        let size = event.as_json().len();
+        if size <= max_event_bytes {
+            return true;
+        }
+        warn!(
+            event_id = %event.id,
+            size,
+            max_event_bytes,
+            "dropping oversized relay event"
+        );
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .oversized_events_dropped += 1;
+        false
+    }
+
+    pub fn retain_admitted(
+        &self,
+        events: impl IntoIterator<Item = RadrootsNostrEvent>,
+        max_event_bytes: usize,
+    ) -> Vec<RadrootsNostrEvent> {
+        events
+            .into_iter()
+            .filter(|event| self.admit_event(event, max_event_bytes))
+            .collect()
+    }
+
+    pub fn snapshot(&self) -> BridgeRelayStatsSnapshot {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn take(&self) -> BridgeRelayStatsSnapshot {
+        std::mem::take(&mut *self.inner.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use radroots_nostr::prelude::{RadrootsNostrEventBuilder, RadrootsNostrKeys};
+
+    use super::BridgeRelayStats;
+    use crate::core::bridge::publish::BridgeRelayPublishResult;
+
+    fn result(
+        relay_url: &str,
+        acknowledged: bool,
+        detail: Option<&str>,
+    ) -> BridgeRelayPublishResult {
+        BridgeRelayPublishResult {
+            relay_url: relay_url.to_string(),
+            acknowledged,
+            detail: detail.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn record_counts_mixed_publish_results_per_relay() {
+        let stats = BridgeRelayStats::new();
+        stats.record(&[
+            result("wss://relay-a.example.com", true, None),
+            result("wss://relay-b.example.com", false, Some("blocked: spam")),
+        ]);
+        stats.record(&[
+            result("wss://relay-a.example.com", true, None),
+            result("wss://relay-b.example.com", true, None),
+        ]);
+
+        let snapshot = stats.snapshot().relays;
+        let relay_a = &snapshot["wss://relay-a.example.com"];
+        assert_eq!((relay_a.accepted, relay_a.rejected), (2, 0));
+        let relay_b = &snapshot["wss://relay-b.example.com"];
+        assert_eq!((relay_b.accepted, relay_b.rejected), (1, 1));
+        assert_eq!(relay_b.last_rejection.as_deref(), Some("blocked: spam"));
+    }
+
+    #[test]
+    fn take_resets_counters() {
+        let stats = BridgeRelayStats::new();
+        stats.record(&[result("wss://relay-a.example.com", false, None)]);
+
+        let taken = stats.take();
+        assert_eq!(taken.relays["wss://relay-a.example.com"].rejected, 1);
+        assert!(stats.snapshot().relays.is_empty());
+    }
+
+    #[test]
+    fn retain_admitted_drops_oversized_events() {
+        let keys = RadrootsNostrKeys::generate();
+        let small = RadrootsNostrEventBuilder::text_note("eggs")
+            .sign_with_keys(&keys)
+            .expect("small event");
+        let large = RadrootsNostrEventBuilder::text_note("x".repeat(4_096))
+            .sign_with_keys(&keys)
+            .expect("large event");
+        let small_id = small.id;
+        let stats = BridgeRelayStats::new();
+
+        let admitted = stats.retain_admitted([small, large], 1_024);
+
+        assert_eq!(admitted.len(), 1);
+        assert_eq!(admitted[0].id, small_id);
+        assert_eq!(stats.snapshot().oversized_events_dropped, 1);
+    }
+}
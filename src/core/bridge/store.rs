@@ -492,7 +492,7 @@ pub fn new_order_request_job(
     )
 }
 
-fn unix_timestamp_now() -> u64 {
+pub(crate) fn unix_timestamp_now() -> u64 {
     std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_else(|_| std::time::Duration::from_secs(0))
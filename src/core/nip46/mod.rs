@@ -1,3 +1,5 @@
+pub mod rate_limit;
 pub mod session;
+pub mod watermark;
 
 pub use session::{Nip46Session, Nip46SessionStore};
@@ -0,0 +1,87 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const NIP46_RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+const NIP46_RATE_LIMIT_PRUNE_AUTHORS: usize = 4_096;
+
+#[derive(Debug, Clone, Default)]
+pub struct Nip46AuthorRateLimiter {
+    inner: Arc<Mutex<HashMap<String, VecDeque<Instant>>>>,
+    dropped: Arc<AtomicU64>,
+    max_per_window: Option<u32>,
+}
+
+impl Nip46AuthorRateLimiter {
+    pub fn new(max_per_minute: Option<u32>) -> Self {
+        Self {
+            max_per_window: max_per_minute,
+            ..Self::default()
+        }
+    }
+
+    pub fn allow(&self, author: &str) -> bool {
+        self.allow_at(author, Instant::now())
+    }
+
+    fn allow_at(&self, author: &str, now: Instant) -> bool {
+        let Some(limit) = self.max_per_window else {
+            return true;
+        };
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if inner.len() >= NIP46_RATE_LIMIT_PRUNE_AUTHORS {
+            inner.retain(|_, hits| {
+                hits.back()
+                    .is_some_and(|last| now.duration_since(*last) < NIP46_RATE_LIMIT_WINDOW)
+            });
+        }
+        let hits = inner.entry(author.to_string()).or_default();
+        while hits
+            .front()
+            .is_some_and(|first| now.duration_since(*first) >= NIP46_RATE_LIMIT_WINDOW)
+        {
+            hits.pop_front();
+        }
+        if hits.len() >= limit as usize {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        hits.push_back(now);
+        true
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Nip46AuthorRateLimiter;
+
+    #[test]
+    fn allow_drops_events_over_limit_within_window() {
+        let limiter = Nip46AuthorRateLimiter::new(Some(3));
+        let start = Instant::now();
+
+        for offset in 0..3 {
+            assert!(limiter.allow_at("alice", start + Duration::from_secs(offset)));
+        }
+        assert!(!limiter.allow_at("alice", start + Duration::from_secs(10)));
+        assert!(limiter.allow_at("bob", start + Duration::from_secs(10)));
+        assert_eq!(limiter.dropped(), 1);
+
+        assert!(limiter.allow_at("alice", start + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn allow_is_unlimited_without_config() {
+        let limiter = Nip46AuthorRateLimiter::new(None);
+        let now = Instant::now();
+        assert!((0..1_000).all(|_| limiter.allow_at("alice", now)));
+        assert_eq!(limiter.dropped(), 0);
+    }
+}
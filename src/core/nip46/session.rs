@@ -1,6 +1,6 @@
 #![forbid(unsafe_code)]
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -14,6 +14,7 @@ use radroots_nostr::prelude::{RadrootsNostrClient, RadrootsNostrKeys, RadrootsNo
 pub struct Nip46SessionStore {
     inner: Arc<Mutex<HashMap<String, Nip46Session>>>,
     used_secrets: Arc<Mutex<HashSet<String>>>,
+    idle_timeout: Option<Duration>,
 }
 
 #[derive(Clone)]
@@ -50,6 +51,7 @@ pub struct Nip46SessionView {
     pub authorized: bool,
     pub auth_url: Option<String>,
     pub expires_in_secs: Option<u64>,
+    pub last_used_secs_ago: u64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signer_authority: Option<Nip46SessionAuthority>,
 }
@@ -76,6 +78,7 @@ pub struct Nip46Session {
     pub url: Option<String>,
     pub image: Option<String>,
     pub expires_at: Option<Instant>,
+    pub last_used_at: Instant,
     pub auth_required: bool,
     pub authorized: bool,
     pub auth_url: Option<String>,
@@ -85,9 +88,16 @@ pub struct Nip46Session {
 
 impl Nip46SessionStore {
     pub fn new() -> Self {
+        Self::with_idle_timeout(None)
+    }
+
+    pub fn with_idle_timeout(idle_timeout_secs: Option<u64>) -> Self {
         Self {
             inner: Arc::new(Mutex::new(HashMap::new())),
             used_secrets: Arc::new(Mutex::new(HashSet::new())),
+            idle_timeout: idle_timeout_secs
+                .filter(|secs| *secs > 0)
+                .map(Duration::from_secs),
         }
     }
 
@@ -98,15 +108,23 @@ impl Nip46SessionStore {
 
     pub async fn get(&self, session_id: &str) -> Option<Nip46Session> {
         let mut sessions = self.inner.lock().await;
-        let expired = sessions
-            .get(session_id)
-            .map(|session| session.is_expired())
-            .unwrap_or(false);
-        if expired {
+        let session = sessions.get(session_id)?;
+        if session.is_stale(self.idle_timeout) {
             sessions.remove(session_id);
             return None;
         }
-        sessions.get(session_id).cloned()
+        Some(session.clone())
+    }
+
+    pub async fn touch(&self, session_id: &str) -> Option<Nip46Session> {
+        let mut sessions = self.inner.lock().await;
+        let session = sessions.get_mut(session_id)?;
+        if session.is_stale(self.idle_timeout) {
+            sessions.remove(session_id);
+            return None;
+        }
+        session.last_used_at = Instant::now();
+        Some(session.clone())
     }
 
     pub async fn remove(&self, session_id: &str) -> bool {
@@ -118,7 +136,7 @@ impl Nip46SessionStore {
         let mut sessions = self.inner.lock().await;
         match sessions.get_mut(session_id) {
             Some(session) => {
-                if session.is_expired() {
+                if session.is_stale(self.idle_timeout) {
                     sessions.remove(session_id);
                     return false;
                 }
@@ -133,7 +151,7 @@ impl Nip46SessionStore {
         let mut sessions = self.inner.lock().await;
         match sessions.get_mut(session_id) {
             Some(session) => {
-                if session.is_expired() {
+                if session.is_stale(self.idle_timeout) {
                     sessions.remove(session_id);
                     return false;
                 }
@@ -151,7 +169,7 @@ impl Nip46SessionStore {
         let mut sessions = self.inner.lock().await;
         match sessions.get_mut(session_id) {
             Some(session) => {
-                if session.is_expired() {
+                if session.is_stale(self.idle_timeout) {
                     sessions.remove(session_id);
                     return None;
                 }
@@ -172,7 +190,7 @@ impl Nip46SessionStore {
         let mut sessions = self.inner.lock().await;
         match sessions.get_mut(session_id) {
             Some(session) => {
-                if session.is_expired() {
+                if session.is_stale(self.idle_timeout) {
                     sessions.remove(session_id);
                     return false;
                 }
@@ -185,7 +203,7 @@ impl Nip46SessionStore {
 
     pub async fn list(&self) -> Vec<Nip46Session> {
         let mut sessions = self.inner.lock().await;
-        sessions.retain(|_, session| !session.is_expired());
+        sessions.retain(|_, session| !session.is_stale(self.idle_timeout));
         let mut listed: Vec<Nip46Session> = sessions.values().cloned().collect();
         listed.sort_by(|left, right| left.id.cmp(&right.id));
         listed
@@ -216,6 +234,13 @@ impl Nip46Session {
             .unwrap_or(false)
     }
 
+    pub fn is_stale(&self, idle_timeout: Option<Duration>) -> bool {
+        self.is_expired()
+            || idle_timeout
+                .map(|idle_timeout| self.last_used_at.elapsed() >= idle_timeout)
+                .unwrap_or(false)
+    }
+
     pub fn role(&self) -> Nip46SessionRole {
         if self.client_keys.public_key() == self.remote_signer_pubkey {
             Nip46SessionRole::InboundLocalSigner
@@ -240,6 +265,7 @@ impl Nip46Session {
             authorized: self.authorized,
             auth_url: self.auth_url.clone(),
             expires_in_secs: self.expires_at.map(remaining_secs),
+            last_used_secs_ago: self.last_used_at.elapsed().as_secs(),
             signer_authority: self.signer_authority.clone(),
         }
     }
@@ -302,6 +328,46 @@ pub fn sign_event_allowed(perms: &[String], kind: u32) -> bool {
     perms.iter().any(|perm| perm == &entry)
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Nip46SignKinds {
+    #[serde(serialize_with = "serialize_all_sign_kinds")]
+    All,
+    Kinds(Vec<u32>),
+}
+
+fn serialize_all_sign_kinds<S: serde::Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str("all")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Nip46SessionCapabilities {
+    pub can_sign_kinds: Nip46SignKinds,
+    pub can_nip04: bool,
+    pub can_nip44: bool,
+}
+
+pub fn session_capabilities(perms: &[String]) -> Nip46SessionCapabilities {
+    let can_sign_kinds = if perms.iter().any(|perm| perm == "sign_event") {
+        Nip46SignKinds::All
+    } else {
+        Nip46SignKinds::Kinds(
+            perms
+                .iter()
+                .filter_map(|perm| perm.strip_prefix("sign_event:")?.parse().ok())
+                .collect::<BTreeSet<u32>>()
+                .into_iter()
+                .collect(),
+        )
+    };
+    let grants = |prefix: &str| perms.iter().any(|perm| perm.starts_with(prefix));
+    Nip46SessionCapabilities {
+        can_sign_kinds,
+        can_nip04: grants("nip04_"),
+        can_nip44: grants("nip44_"),
+    }
+}
+
 pub fn session_expires_at(ttl_secs: u64) -> Option<Instant> {
     if ttl_secs == 0 {
         None
@@ -332,6 +398,7 @@ mod tests {
             url: None,
             image: None,
             expires_at,
+            last_used_at: Instant::now(),
             auth_required: false,
             authorized: true,
             auth_url: None,
@@ -381,6 +448,7 @@ mod tests {
             url: Some("https://signer.example.com".to_string()),
             image: None,
             expires_at: Some(Instant::now() + Duration::from_secs(30)),
+            last_used_at: Instant::now(),
             auth_required: true,
             authorized: false,
             auth_url: Some("https://signer.example.com/auth".to_string()),
@@ -420,6 +488,7 @@ mod tests {
             url: None,
             image: None,
             expires_at: Some(Instant::now() + Duration::from_secs(30)),
+            last_used_at: Instant::now(),
             auth_required: false,
             authorized: true,
             auth_url: None,
@@ -439,6 +508,45 @@ mod tests {
         assert_ne!(view.signer_pubkey, expected_user_pubkey);
     }
 
+    #[tokio::test]
+    async fn session_store_touch_updates_last_used() {
+        let store = Nip46SessionStore::new();
+        let mut session = build_session("touched", None);
+        session.last_used_at = Instant::now() - Duration::from_secs(120);
+        store.insert(session).await;
+
+        let found = store.touch("touched").await.expect("session");
+
+        assert!(found.last_used_at.elapsed() < Duration::from_secs(5));
+        assert_eq!(found.public_view().last_used_secs_ago, 0);
+    }
+
+    #[tokio::test]
+    async fn session_store_get_leaves_last_used_untouched() {
+        let store = Nip46SessionStore::new();
+        let mut session = build_session("read", None);
+        session.last_used_at = Instant::now() - Duration::from_secs(120);
+        store.insert(session).await;
+
+        let found = store.get("read").await.expect("session");
+
+        assert!(found.public_view().last_used_secs_ago >= 120);
+    }
+
+    #[tokio::test]
+    async fn session_store_removes_idle_sessions_before_ttl() {
+        let store = Nip46SessionStore::with_idle_timeout(Some(60));
+        let mut dormant = build_session("dormant", Some(Instant::now() + Duration::from_secs(900)));
+        dormant.last_used_at = Instant::now() - Duration::from_secs(61);
+        store.insert(dormant).await;
+        store.insert(build_session("recent", None)).await;
+
+        assert!(store.get("dormant").await.is_none());
+        let listed = store.list().await;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, "recent");
+    }
+
     #[tokio::test]
     async fn session_store_keeps_active() {
         let store = Nip46SessionStore::new();
@@ -487,6 +595,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn session_capabilities_interpret_perm_strings() {
+        let perms = vec![
+            "sign_event:4".to_string(),
+            "sign_event:1".to_string(),
+            "sign_event:4".to_string(),
+            "sign_event:bogus".to_string(),
+            "nip44_decrypt".to_string(),
+        ];
+        let capabilities = session_capabilities(&perms);
+        assert_eq!(
+            capabilities.can_sign_kinds,
+            Nip46SignKinds::Kinds(vec![1, 4])
+        );
+        assert!(!capabilities.can_nip04);
+        assert!(capabilities.can_nip44);
+
+        let capabilities =
+            session_capabilities(&["sign_event".to_string(), "nip04_encrypt".to_string()]);
+        assert_eq!(capabilities.can_sign_kinds, Nip46SignKinds::All);
+        assert!(capabilities.can_nip04);
+        assert!(!capabilities.can_nip44);
+        assert_eq!(
+            serde_json::to_value(&capabilities).expect("json")["can_sign_kinds"],
+            "all"
+        );
+        assert_eq!(
+            serde_json::to_value(session_capabilities(&[])).expect("json")["can_sign_kinds"],
+            serde_json::json!([])
+        );
+    }
+
     #[test]
     fn sign_event_allowed_respects_kinds() {
         let perms = vec!["sign_event:1".to_string()];
@@ -0,0 +1,239 @@
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::Notify;
+
+const NIP46_WATERMARK_VERSION: u32 = 1;
+const NIP46_WATERMARK_RECENT_CAPACITY: usize = 512;
+const NIP46_WATERMARK_OVERLAP_SECS: u64 = 30;
+const NIP46_WATERMARK_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+const NIP46_WATERMARK_FLUSH_EVENTS: usize = 32;
+
+#[derive(Debug, Clone, Default)]
+pub struct Nip46Watermark {
+    inner: Arc<Mutex<Nip46WatermarkInner>>,
+    path: Option<Arc<PathBuf>>,
+    flush_requested: Arc<Notify>,
+}
+
+#[derive(Debug, Default)]
+struct Nip46WatermarkInner {
+    last_processed_unix: Option<u64>,
+    recent: VecDeque<String>,
+    seen: HashSet<String>,
+    unflushed: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedNip46Watermark {
+    version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_processed_unix: Option<u64>,
+    #[serde(default)]
+    recent: VecDeque<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum Nip46WatermarkError {
+    #[error("invalid nip46 watermark path: {0}")]
+    InvalidStatePath(PathBuf),
+    #[error("unsupported nip46 watermark version: {0}")]
+    UnsupportedStateVersion(u32),
+    #[error("nip46 watermark io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("nip46 watermark json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("nip46 watermark flush task failed: {0}")]
+    Join(#[from] tokio::task::JoinError),
+}
+
+impl Nip46Watermark {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Result<Self, Nip46WatermarkError> {
+        let mut inner = Nip46WatermarkInner::default();
+        if path.exists() {
+            let payload = std::fs::read_to_string(&path)?;
+            let snapshot: PersistedNip46Watermark = serde_json::from_str(&payload)?;
+            if snapshot.version != NIP46_WATERMARK_VERSION {
+                return Err(Nip46WatermarkError::UnsupportedStateVersion(
+                    snapshot.version,
+                ));
+            }
+            inner.last_processed_unix = snapshot.last_processed_unix;
+            for key in snapshot.recent {
+                inner.remember(key);
+            }
+        }
+        Ok(Self {
+            inner: Arc::new(Mutex::new(inner)),
+            path: Some(Arc::new(path)),
+            flush_requested: Arc::default(),
+        })
+    }
+
+    pub fn last_processed_unix(&self) -> Option<u64> {
+        self.lock().last_processed_unix
+    }
+
+    pub fn resubscribe_since(&self, now_unix: u64) -> u64 {
+        resubscribe_since(
+            self.last_processed_unix(),
+            now_unix,
+            NIP46_WATERMARK_OVERLAP_SECS,
+        )
+    }
+
+    pub fn record(&self, key: String, created_at_unix: u64) -> bool {
+        let mut inner = self.lock();
+        if inner.seen.contains(&key) {
+            return false;
+        }
+        inner.remember(key);
+        inner.last_processed_unix = Some(
+            inner
+                .last_processed_unix
+                .map_or(created_at_unix, |last| last.max(created_at_unix)),
+        );
+        inner.unflushed += 1;
+        if inner.unflushed >= NIP46_WATERMARK_FLUSH_EVENTS {
+            self.flush_requested.notify_one();
+        }
+        true
+    }
+
+    pub async fn wait_for_flush(&self) {
+        let _ = tokio::time::timeout(
+            NIP46_WATERMARK_FLUSH_INTERVAL,
+            self.flush_requested.notified(),
+        )
+        .await;
+    }
+
+    pub async fn flush(&self) -> Result<(), Nip46WatermarkError> {
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        let Some(snapshot) = self.take_unflushed() else {
+            return Ok(());
+        };
+        let payload = serde_json::to_vec(&snapshot)?;
+        let result = tokio::task::spawn_blocking(move || persist(&path, &payload))
+            .await
+            .unwrap_or_else(|error| Err(error.into()));
+        if result.is_err() {
+            self.lock().unflushed += 1;
+        }
+        result
+    }
+
+    fn take_unflushed(&self) -> Option<PersistedNip46Watermark> {
+        let mut inner = self.lock();
+        if inner.unflushed == 0 {
+            return None;
+        }
+        inner.unflushed = 0;
+        Some(PersistedNip46Watermark {
+            version: NIP46_WATERMARK_VERSION,
+            last_processed_unix: inner.last_processed_unix,
+            recent: inner.recent.clone(),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Nip46WatermarkInner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Nip46WatermarkInner {
+    fn remember(&mut self, key: String) {
+        if !self.seen.insert(key.clone()) {
+            return;
+        }
+        self.recent.push_back(key);
+        while self.recent.len() > NIP46_WATERMARK_RECENT_CAPACITY {
+            if let Some(evicted) = self.recent.pop_front() {
+                self.seen.remove(&evicted);
+            }
+        }
+    }
+}
+
+fn resubscribe_since(last_processed_unix: Option<u64>, now_unix: u64, overlap_secs: u64) -> u64 {
+    match last_processed_unix {
+        Some(last) => last.min(now_unix).saturating_sub(overlap_secs),
+        None => now_unix,
+    }
+}
+
+fn persist(path: &Path, payload: &[u8]) -> Result<(), Nip46WatermarkError> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| Nip46WatermarkError::InvalidStatePath(path.to_path_buf()))?;
+    let temp_path = path.with_file_name(format!("{}.tmp", file_name.to_string_lossy()));
+    std::fs::write(&temp_path, payload)?;
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Nip46Watermark, resubscribe_since};
+
+    #[test]
+    fn resubscribe_since_starts_now_without_watermark() {
+        assert_eq!(resubscribe_since(None, 1_000, 30), 1_000);
+    }
+
+    #[test]
+    fn resubscribe_since_reaches_back_over_the_gap() {
+        assert_eq!(resubscribe_since(Some(900), 1_000, 30), 870);
+        assert_eq!(resubscribe_since(Some(10), 1_000, 30), 0);
+    }
+
+    #[test]
+    fn resubscribe_since_clamps_future_watermark_to_now() {
+        assert_eq!(resubscribe_since(Some(5_000), 1_000, 30), 970);
+    }
+
+    #[test]
+    fn record_dedupes_request_ids_and_advances_watermark() {
+        let watermark = Nip46Watermark::new();
+        assert!(watermark.record("client:req-1".to_string(), 100));
+        assert!(!watermark.record("client:req-1".to_string(), 100));
+        assert!(watermark.record("client:req-2".to_string(), 90));
+        assert_eq!(watermark.last_processed_unix(), Some(100));
+    }
+
+    #[tokio::test]
+    async fn load_restores_flushed_watermark_and_dedup_set() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("radrootsd-nip46-watermark-{nanos}.json"));
+        let watermark = Nip46Watermark::load(path.clone()).expect("load empty");
+        assert!(watermark.record("client:req-1".to_string(), 1_234));
+        assert!(!path.exists());
+
+        watermark.flush().await.expect("flush");
+        let payload = std::fs::read_to_string(&path).expect("read");
+        assert!(!payload.contains('\n'));
+
+        let reloaded = Nip46Watermark::load(path.clone()).expect("reload");
+        assert_eq!(reloaded.last_processed_unix(), Some(1_234));
+        assert!(!reloaded.record("client:req-1".to_string(), 1_234));
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::{Arc, RwLock};
+
 use anyhow::Result;
 use radroots_identity::RadrootsIdentity;
 use radroots_nostr::prelude::{
@@ -5,7 +7,7 @@ use radroots_nostr::prelude::{
 };
 use radroots_nostr_signer::prelude::RadrootsNostrEmbeddedSignerBackend;
 
-use crate::app::config::{BridgeConfig, Nip46Config};
+use crate::app::config::{BridgeConfig, Nip46Config, Settings};
 
 #[derive(Clone)]
 pub struct Radrootsd {
@@ -16,9 +18,17 @@ pub struct Radrootsd {
     pub info: serde_json::Value,
     pub bridge_signer: RadrootsNostrEmbeddedSignerBackend,
     pub(crate) bridge_jobs: crate::core::bridge::store::BridgeJobStore,
+    pub(crate) bridge_coordinates: crate::core::bridge::coordinate::BridgeCoordinateLocks,
+    pub(crate) bridge_relay_stats: crate::core::bridge::relay_stats::BridgeRelayStats,
+    pub(crate) bridge_relay_errors: crate::core::bridge::relay_errors::BridgeRelayErrorLog,
+    pub(crate) bridge_fallback_client: RadrootsNostrClient,
     pub bridge_config: BridgeConfig,
     pub(crate) nip46_sessions: crate::core::nip46::session::Nip46SessionStore,
+    pub(crate) nip46_watermark: crate::core::nip46::watermark::Nip46Watermark,
+    pub(crate) nip46_author_limiter: crate::core::nip46::rate_limit::Nip46AuthorRateLimiter,
     pub nip46_config: Nip46Config,
+    pub(crate) settings: Arc<RwLock<Option<Settings>>>,
+    pub(crate) pending_restart: Arc<RwLock<Vec<String>>>,
 }
 
 impl Radrootsd {
@@ -31,6 +41,7 @@ impl Radrootsd {
         let keys: RadrootsNostrKeys = identity.keys().clone();
         let pubkey = keys.public_key();
         let client = RadrootsNostrClient::new(keys.clone());
+        let bridge_fallback_client = RadrootsNostrClient::new(keys.clone());
         let info = serde_json::json!({
             "version": env!("CARGO_PKG_VERSION"),
             "build": option_env!("GIT_HASH").unwrap_or("unknown"),
@@ -53,7 +64,25 @@ impl Radrootsd {
             crate::core::bridge::store::BridgeJobStore::new(bridge_config.job_status_retention);
         #[cfg(not(test))]
         let bridge_jobs = bridge_jobs.store;
-        let nip46_sessions = crate::core::nip46::session::Nip46SessionStore::new();
+        let bridge_coordinates = crate::core::bridge::coordinate::BridgeCoordinateLocks::new();
+        let bridge_relay_stats = crate::core::bridge::relay_stats::BridgeRelayStats::new();
+        let bridge_relay_errors = crate::core::bridge::relay_errors::BridgeRelayErrorLog::new(
+            bridge_config.relay_error_history,
+        );
+        let nip46_sessions = crate::core::nip46::session::Nip46SessionStore::with_idle_timeout(
+            nip46_config.session_idle_timeout_secs,
+        );
+        #[cfg(not(test))]
+        let nip46_watermark = crate::core::nip46::watermark::Nip46Watermark::load(
+            bridge_config
+                .state_path
+                .with_file_name("nip46-watermark.json"),
+        )?;
+        #[cfg(test)]
+        let nip46_watermark = crate::core::nip46::watermark::Nip46Watermark::new();
+        let nip46_author_limiter = crate::core::nip46::rate_limit::Nip46AuthorRateLimiter::new(
+            nip46_config.listener_max_events_per_author_per_minute,
+        );
 
         Ok(Self {
             client,
@@ -63,11 +92,44 @@ impl Radrootsd {
             info,
             bridge_signer,
             bridge_jobs,
+            bridge_coordinates,
+            bridge_relay_stats,
+            bridge_relay_errors,
+            bridge_fallback_client,
             bridge_config,
             nip46_sessions,
+            nip46_watermark,
+            nip46_author_limiter,
             nip46_config,
+            settings: Arc::default(),
+            pending_restart: Arc::default(),
         })
     }
+
+    pub fn set_settings(&self, settings: Settings) {
+        *self.settings.write().unwrap_or_else(|e| e.into_inner()) = Some(settings);
+    }
+
+    pub(crate) fn settings(&self) -> Option<Settings> {
+        self.settings
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub fn set_pending_restart(&self, sections: Vec<String>) {
+        *self
+            .pending_restart
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = sections;
+    }
+
+    pub(crate) fn pending_restart(&self) -> Vec<String> {
+        self.pending_restart
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
 }
 
 #[cfg(test)]
@@ -100,6 +162,9 @@ mod tests {
             state.bridge_jobs.snapshot().capacity,
             bridge_cfg.job_status_retention
         );
+        assert!(state.bridge_coordinates.is_empty());
+        assert!(state.bridge_relay_stats.snapshot().relays.is_empty());
+        assert!(state.bridge_relay_errors.snapshot().is_empty());
         assert_eq!(state.nip46_config.session_ttl_secs, cfg.session_ttl_secs);
         assert_eq!(state.nip46_config.perms, cfg.perms);
         assert_eq!(state.info["version"], env!("CARGO_PKG_VERSION"));
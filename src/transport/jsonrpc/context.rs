@@ -1,17 +1,128 @@
 #![forbid(unsafe_code)]
 
+use std::time::{Duration, Instant};
+
+use jsonrpsee::core::server::Extensions;
+
 use crate::core::Radrootsd;
 
+use super::deadline::{RequestDeadline, clamp_to_deadline};
+use super::error::RpcError;
 use super::registry::MethodRegistry;
 
 #[derive(Clone)]
 pub struct RpcContext {
     pub state: Radrootsd,
     pub methods: MethodRegistry,
+    deadline: Option<RequestDeadline>,
 }
 
 impl RpcContext {
     pub fn new(state: Radrootsd, methods: MethodRegistry) -> Self {
-        Self { state, methods }
+        Self {
+            state,
+            methods,
+            deadline: None,
+        }
+    }
+
+    pub(crate) fn with_deadline(mut self, extensions: &Extensions) -> Self {
+        self.deadline = extensions.get::<RequestDeadline>().copied();
+        self
+    }
+
+    pub(crate) fn fetch_timeout(&self, timeout: Duration) -> Duration {
+        clamp_to_deadline(timeout, self.deadline, Instant::now())
+    }
+
+    pub async fn relay_counts(&self) -> (usize, usize) {
+        let relays = self.state.client.relays().await;
+        let connected = relays.values().filter(|relay| relay.is_connected()).count();
+        (relays.len(), connected)
+    }
+
+    pub async fn require_relays(&self) -> Result<(), RpcError> {
+        let (configured, connected) = self.relay_counts().await;
+        if connected == 0 {
+            return Err(RpcError::NoRelays {
+                configured,
+                connected,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use jsonrpsee::core::server::Extensions;
+    use radroots_identity::RadrootsIdentity;
+    use radroots_nostr::prelude::RadrootsNostrMetadata;
+
+    use super::RpcContext;
+    use crate::app::config::{BridgeConfig, Nip46Config};
+    use crate::core::Radrootsd;
+    use crate::transport::jsonrpc::deadline::RequestDeadline;
+    use crate::transport::jsonrpc::{MethodRegistry, RpcError};
+
+    fn ctx() -> RpcContext {
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let state = Radrootsd::new(
+            RadrootsIdentity::generate(),
+            metadata,
+            BridgeConfig::default(),
+            Nip46Config::default(),
+        )
+        .expect("state");
+        RpcContext::new(state, MethodRegistry::default())
+    }
+
+    #[test]
+    fn fetch_timeout_uses_deadline_from_extensions() {
+        let timeout = Duration::from_secs(10);
+        let mut extensions = Extensions::new();
+        assert_eq!(
+            ctx().with_deadline(&extensions).fetch_timeout(timeout),
+            timeout
+        );
+
+        extensions.insert(RequestDeadline(Instant::now() + Duration::from_secs(2)));
+        let clamped = ctx().with_deadline(&extensions).fetch_timeout(timeout);
+        assert!(clamped <= Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn require_relays_reports_none_configured() {
+        let ctx = ctx();
+        let err = ctx.require_relays().await.expect_err("no relays");
+        assert!(matches!(
+            err,
+            RpcError::NoRelays {
+                configured: 0,
+                connected: 0
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn require_relays_reports_configured_but_disconnected() {
+        let ctx = ctx();
+        ctx.state
+            .client
+            .add_relay("wss://relay.example.com")
+            .await
+            .expect("add relay");
+        assert_eq!(ctx.relay_counts().await, (1, 0));
+        let err = ctx.require_relays().await.expect_err("disconnected relay");
+        assert!(matches!(
+            err,
+            RpcError::NoRelays {
+                configured: 1,
+                connected: 0
+            }
+        ));
     }
 }
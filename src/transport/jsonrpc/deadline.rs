@@ -0,0 +1,66 @@
+#![forbid(unsafe_code)]
+
+use std::time::{Duration, Instant};
+
+pub(crate) const REQUEST_TIMEOUT_HEADER: &str = "x-request-timeout-ms";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RequestDeadline(pub Instant);
+
+pub(crate) fn request_deadline(
+    header: Option<&str>,
+    received_at: Instant,
+) -> Option<RequestDeadline> {
+    let millis = header?.trim().parse::<u64>().ok()?;
+    Some(RequestDeadline(received_at + Duration::from_millis(millis)))
+}
+
+pub(crate) fn clamp_to_deadline(
+    timeout: Duration,
+    deadline: Option<RequestDeadline>,
+    now: Instant,
+) -> Duration {
+    match deadline {
+        Some(RequestDeadline(deadline)) => timeout.min(deadline.saturating_duration_since(now)),
+        None => timeout,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{RequestDeadline, clamp_to_deadline, request_deadline};
+
+    #[test]
+    fn request_deadline_parses_millisecond_budget() {
+        let now = Instant::now();
+        assert_eq!(
+            request_deadline(Some(" 1500 "), now),
+            Some(RequestDeadline(now + Duration::from_millis(1500)))
+        );
+        assert_eq!(request_deadline(Some("soon"), now), None);
+        assert_eq!(request_deadline(None, now), None);
+    }
+
+    #[test]
+    fn fetch_timeout_is_clamped_by_remaining_deadline() {
+        let now = Instant::now();
+        let timeout = Duration::from_secs(10);
+        let deadline = Some(RequestDeadline(now + Duration::from_secs(2)));
+
+        assert_eq!(clamp_to_deadline(timeout, None, now), timeout);
+        assert_eq!(
+            clamp_to_deadline(timeout, deadline, now),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            clamp_to_deadline(Duration::from_secs(1), deadline, now),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            clamp_to_deadline(timeout, deadline, now + Duration::from_secs(3)),
+            Duration::ZERO
+        );
+    }
+}
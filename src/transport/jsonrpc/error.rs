@@ -7,14 +7,21 @@ use thiserror::Error;
 pub enum RpcError {
     #[error("failed to add relay {0}: {1}")]
     AddRelay(String, String),
-    #[error("no relays configured; call relays.add first")]
-    NoRelays,
+    #[error("no relays available: {connected} connected of {configured} configured")]
+    NoRelays { configured: usize, connected: usize },
     #[error("invalid params: {0}")]
     InvalidParams(String),
-    #[error("method not found: {0}")]
-    MethodNotFound(String),
+    #[error("method not found: {method}")]
+    MethodNotFound {
+        method: String,
+        did_you_mean: Option<String>,
+    },
     #[error("unauthorized: {0}")]
     Unauthorized(String),
+    #[error("insufficient relays for publish: {connected} connected, {required} required")]
+    InsufficientRelays { required: usize, connected: usize },
+    #[error("method {0} is at its concurrency limit")]
+    ConcurrencyLimit(String),
     #[error("{0}")]
     Other(String),
 }
@@ -22,14 +29,101 @@ pub enum RpcError {
 impl From<RpcError> for ErrorObjectOwned {
     fn from(err: RpcError) -> Self {
         match err {
+            RpcError::NoRelays {
+                configured,
+                connected,
+            } => ErrorObject::owned(
+                -32000,
+                err.to_string(),
+                Some(serde_json::json!({
+                    "reason": if configured == 0 { "none_configured" } else { "disconnected" },
+                    "configured": configured,
+                    "connected": connected,
+                })),
+            ),
             RpcError::InvalidParams(msg) => ErrorObject::owned(-32602, msg, None::<()>),
-            RpcError::MethodNotFound(name) => {
-                ErrorObject::owned(-32601, format!("method not found: {name}"), None::<()>)
-            }
+            RpcError::MethodNotFound {
+                ref method,
+                ref did_you_mean,
+            } => ErrorObject::owned(
+                -32601,
+                format!("method not found: {method}"),
+                did_you_mean
+                    .as_ref()
+                    .map(|suggestion| serde_json::json!({ "did_you_mean": suggestion })),
+            ),
             RpcError::Unauthorized(msg) => {
                 ErrorObject::owned(-32001, format!("unauthorized: {msg}"), None::<()>)
             }
+            RpcError::InsufficientRelays {
+                required,
+                connected,
+            } => ErrorObject::owned(
+                -32006,
+                err.to_string(),
+                Some(serde_json::json!({
+                    "required": required,
+                    "connected": connected,
+                    "retryable": true,
+                })),
+            ),
+            RpcError::ConcurrencyLimit(_) => {
+                ErrorObject::owned(-32005, err.to_string(), None::<()>)
+            }
             other => ErrorObject::owned(-32000, other.to_string(), None::<()>),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use jsonrpsee::types::ErrorObjectOwned;
+
+    use super::RpcError;
+
+    fn error_data(err: RpcError) -> serde_json::Value {
+        let object = ErrorObjectOwned::from(err);
+        assert_eq!(object.code(), -32000);
+        serde_json::from_str(object.data().expect("error data").get()).expect("json data")
+    }
+
+    #[test]
+    fn no_relays_reports_none_configured() {
+        let data = error_data(RpcError::NoRelays {
+            configured: 0,
+            connected: 0,
+        });
+        assert_eq!(data["reason"], "none_configured");
+        assert_eq!(data["configured"], 0);
+    }
+
+    #[test]
+    fn no_relays_reports_configured_but_disconnected() {
+        let err = RpcError::NoRelays {
+            configured: 3,
+            connected: 0,
+        };
+        assert_eq!(
+            err.to_string(),
+            "no relays available: 0 connected of 3 configured"
+        );
+        let data = error_data(err);
+        assert_eq!(data["reason"], "disconnected");
+        assert_eq!(data["configured"], 3);
+        assert_eq!(data["connected"], 0);
+    }
+
+    #[test]
+    fn insufficient_relays_is_distinct_and_retryable() {
+        let object = ErrorObjectOwned::from(RpcError::InsufficientRelays {
+            required: 3,
+            connected: 1,
+        });
+        assert_eq!(object.code(), -32006);
+        let data: serde_json::Value =
+            serde_json::from_str(object.data().expect("error data").get()).expect("json data");
+        assert_eq!(data["required"], 3);
+        assert_eq!(data["connected"], 1);
+        assert_eq!(data["retryable"], true);
+    }
+}
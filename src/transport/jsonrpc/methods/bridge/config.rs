@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::app::config::{
+    BridgeConfig, Configuration, IdentitySource, MetadataPubkeyMismatchPolicy, Nip46Config,
+    RpcConfig, Settings,
+};
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+const REDACTED: &str = "[redacted]";
+const SECRET_FIELDS: &[&str] = &["bridge.bearer_token", "nip46.nostrconnect_url"];
+
+#[derive(Clone, Debug, Serialize)]
+struct BridgeConfigResponse {
+    config: Value,
+    metadata: Value,
+    sources: BTreeMap<String, &'static str>,
+    pending_restart: Vec<String>,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.config");
+    m.register_async_method("bridge.config", |_params, ctx, extensions| async move {
+        require_bridge_auth(&extensions)?;
+        let settings = ctx
+            .state
+            .settings()
+            .ok_or_else(|| RpcError::Other("effective config is not available".to_string()))?;
+        effective_config(&settings, ctx.state.pending_restart())
+    })?;
+    Ok(())
+}
+
+fn effective_config(
+    settings: &Settings,
+    pending_restart: Vec<String>,
+) -> Result<BridgeConfigResponse, RpcError> {
+    let defaults = to_value(&Configuration {
+        service: settings.config.service.clone(),
+        rpc: RpcConfig::default(),
+        rpc_addr: None,
+        identity_source: IdentitySource::default(),
+        metadata_pubkey_mismatch: MetadataPubkeyMismatchPolicy::default(),
+        merge_startup_metadata: false,
+        max_relays: None,
+        nip46: Nip46Config::default(),
+        bridge: BridgeConfig::default(),
+    })?;
+    // Service fields have no daemon-side default, so they always count as configured.
+    let service = to_value(&settings.config.service)?;
+    let mut config = to_value(&settings.config)?;
+    let mut sources = BTreeMap::new();
+    if let Value::Object(fields) = &mut config {
+        for (field, value) in fields.iter_mut() {
+            let default = defaults.get(field).filter(|_| service.get(field).is_none());
+            match (value, default) {
+                (Value::Object(section), Some(Value::Object(section_defaults))) => {
+                    for (name, value) in section.iter_mut() {
+                        annotate(
+                            format!("{field}.{name}"),
+                            value,
+                            section_defaults.get(name),
+                            &mut sources,
+                        );
+                    }
+                }
+                (value, default) => annotate(field.clone(), value, default, &mut sources),
+            }
+        }
+    }
+    Ok(BridgeConfigResponse {
+        config,
+        metadata: to_value(&settings.metadata)?,
+        sources,
+        pending_restart,
+    })
+}
+
+fn annotate(
+    path: String,
+    value: &mut Value,
+    default: Option<&Value>,
+    sources: &mut BTreeMap<String, &'static str>,
+) {
+    let source = if default == Some(&*value) {
+        "default"
+    } else {
+        "configured"
+    };
+    if !value.is_null() && SECRET_FIELDS.contains(&path.as_str()) {
+        *value = Value::String(REDACTED.to_string());
+    }
+    sources.insert(path, source);
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, RpcError> {
+    serde_json::to_value(value)
+        .map_err(|error| RpcError::Other(format!("failed to serialize config: {error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use radroots_nostr::prelude::RadrootsNostrMetadata;
+    use radroots_runtime::RadrootsNostrServiceConfig;
+
+    use crate::app::config::{
+        BridgeConfig, Configuration, IdentitySource, MetadataPubkeyMismatchPolicy, Nip46Config,
+        RpcConfig, Settings,
+    };
+
+    use super::effective_config;
+
+    #[test]
+    fn effective_config_redacts_secrets_and_marks_sources() {
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let settings = Settings {
+            metadata,
+            config: Configuration {
+                service: RadrootsNostrServiceConfig {
+                    logs_dir: "/var/log/radrootsd".to_string(),
+                    relays: vec!["wss://relay.example.com".to_string()],
+                    nip89_identifier: None,
+                    nip89_extra_tags: Vec::new(),
+                },
+                rpc: RpcConfig::default(),
+                rpc_addr: None,
+                identity_source: IdentitySource::File,
+                metadata_pubkey_mismatch: MetadataPubkeyMismatchPolicy::Fail,
+                merge_startup_metadata: false,
+                max_relays: Some(4),
+                nip46: Nip46Config {
+                    nostrconnect_url: Some("nostrconnect://abc?secret=s3cr3t".to_string()),
+                    ..Nip46Config::default()
+                },
+                bridge: BridgeConfig {
+                    enabled: true,
+                    bearer_token: Some("super-secret".to_string()),
+                    max_tags: 500,
+                    ..BridgeConfig::default()
+                },
+            },
+        };
+
+        let response =
+            effective_config(&settings, vec!["config.bridge.enabled".to_string()]).expect("config");
+        let rendered = serde_json::to_string(&response).expect("json");
+
+        assert!(!rendered.contains("super-secret"));
+        assert!(!rendered.contains("s3cr3t"));
+        assert_eq!(response.config["bridge"]["bearer_token"], "[redacted]");
+        assert_eq!(response.config["nip46"]["nostrconnect_url"], "[redacted]");
+        assert_eq!(response.config["bridge"]["max_tags"], 500);
+        assert_eq!(response.sources["bridge.max_tags"], "configured");
+        assert_eq!(response.sources["bridge.connect_timeout_secs"], "default");
+        assert_eq!(response.config["nip46"]["session_ttl_secs"], 900);
+        assert_eq!(response.config["max_relays"], 4);
+        assert_eq!(response.sources["max_relays"], "configured");
+        assert_eq!(response.config["relays"][0], "wss://relay.example.com");
+        assert_eq!(response.sources["relays"], "configured");
+        assert_eq!(response.sources["rpc.addr"], "default");
+        assert_eq!(response.sources["identity_source"], "default");
+        assert_eq!(response.metadata["name"], "radrootsd-test");
+        assert_eq!(response.pending_restart, vec!["config.bridge.enabled"]);
+    }
+}
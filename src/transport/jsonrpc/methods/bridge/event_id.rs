@@ -0,0 +1,98 @@
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use radroots_nostr::prelude::radroots_nostr_parse_pubkey;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+#[derive(Debug, Deserialize)]
+struct BridgeEventIdParams {
+    pubkey: String,
+    created_at: u64,
+    kind: u16,
+    #[serde(default)]
+    tags: Vec<Vec<String>>,
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct BridgeEventIdResponse {
+    event_id: String,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.event.id");
+    m.register_async_method("bridge.event.id", |params, _ctx, extensions| async move {
+        require_bridge_auth(&extensions)?;
+        let params: BridgeEventIdParams = params
+            .parse()
+            .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+        let pubkey = radroots_nostr_parse_pubkey(params.pubkey.trim())
+            .map_err(|error| RpcError::InvalidParams(format!("pubkey is invalid: {error}")))?;
+        let event_id = compute_event_id(
+            &pubkey.to_hex(),
+            params.created_at,
+            params.kind,
+            &params.tags,
+            &params.content,
+        )?;
+        Ok::<BridgeEventIdResponse, RpcError>(BridgeEventIdResponse { event_id })
+    })?;
+    Ok(())
+}
+
+// NIP-01: sha256 over the compact JSON array [0, pubkey, created_at, kind, tags, content].
+fn compute_event_id(
+    pubkey_hex: &str,
+    created_at: u64,
+    kind: u16,
+    tags: &[Vec<String>],
+    content: &str,
+) -> Result<String, RpcError> {
+    let serialized = serde_json::to_vec(&(0, pubkey_hex, created_at, kind, tags, content))
+        .map_err(|error| RpcError::Other(format!("failed to serialize event: {error}")))?;
+    let digest = Sha256::digest(serialized);
+    Ok(format!("{digest:x}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use radroots_nostr::prelude::{RadrootsNostrEventBuilder, RadrootsNostrKeys};
+
+    use super::compute_event_id;
+
+    const PUBKEY: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn compute_event_id_matches_known_vector() {
+        let tags = vec![
+            vec!["t".to_string(), "eggs".to_string()],
+            vec!["p".to_string(), PUBKEY.to_string()],
+        ];
+        let event_id =
+            compute_event_id(PUBKEY, 1_700_000_000, 1, &tags, "fresh eggs\n\"today\"").expect("id");
+        assert_eq!(
+            event_id,
+            "4fdc7330a44f909281ebc5ec816c0bed65658193f79778d3472e314c5472e953"
+        );
+    }
+
+    #[test]
+    fn compute_event_id_matches_signed_event() {
+        let event = RadrootsNostrEventBuilder::text_note("raw milk pickup friday")
+            .sign_with_keys(&RadrootsNostrKeys::generate())
+            .expect("event");
+        let event_id = compute_event_id(
+            &event.pubkey.to_hex(),
+            event.created_at.as_u64(),
+            event.kind.as_u16(),
+            &[],
+            &event.content,
+        )
+        .expect("id");
+        assert_eq!(event_id, event.id.to_hex());
+    }
+}
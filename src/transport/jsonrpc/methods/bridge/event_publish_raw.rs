@@ -0,0 +1,248 @@
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use radroots_nostr::prelude::RadrootsNostrEvent;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::core::bridge::delegation::validate_delegated_event;
+use crate::core::bridge::publish::{BridgePublishSettings, connect_and_publish_event};
+use crate::core::bridge::store::new_publish_job;
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::methods::bridge::shared::{
+    BridgePublishResponse, ensure_bridge_enabled, ensure_bridge_tag_limit, ensure_publish_relays,
+    normalize_idempotency_key, reserve_bridge_job,
+};
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+const PRESIGNED_SIGNER_MODE: &str = "presigned_event";
+
+#[derive(Debug, Deserialize)]
+struct BridgeEventPublishRawParams {
+    event: RadrootsNostrEvent,
+    #[serde(default)]
+    idempotency_key: Option<String>,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.event.publish_raw");
+    m.register_async_method(
+        "bridge.event.publish_raw",
+        |params, ctx, extensions| async move {
+            require_bridge_auth(&extensions)?;
+            let params: BridgeEventPublishRawParams = params
+                .parse()
+                .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+            let response = publish_raw_event(ctx.as_ref().clone(), params).await?;
+            Ok::<BridgePublishResponse, RpcError>(response)
+        },
+    )?;
+    Ok(())
+}
+
+async fn publish_raw_event(
+    ctx: RpcContext,
+    params: BridgeEventPublishRawParams,
+) -> Result<BridgePublishResponse, RpcError> {
+    ensure_bridge_enabled(&ctx)?;
+    let idempotency_key = normalize_idempotency_key(params.idempotency_key)?;
+    let event = params.event;
+    event
+        .verify()
+        .map_err(|error| RpcError::InvalidParams(format!("invalid signed event: {error}")))?;
+    ensure_bridge_tag_limit(&ctx.state.bridge_config, &event)?;
+    validate_delegated_event(&event).map_err(RpcError::InvalidParams)?;
+    publish_presigned_event(&ctx, "bridge.event.publish_raw", event, idempotency_key).await
+}
+
+pub(super) async fn publish_presigned_event(
+    ctx: &RpcContext,
+    command: &'static str,
+    event: RadrootsNostrEvent,
+    idempotency_key: Option<String>,
+) -> Result<BridgePublishResponse, RpcError> {
+    let event_id = event.id.to_hex();
+
+    let reserved = reserve_bridge_job(
+        ctx,
+        new_publish_job(
+            command,
+            Uuid::new_v4().to_string(),
+            idempotency_key,
+            PRESIGNED_SIGNER_MODE.to_string(),
+            event.kind.as_u16() as u32,
+            Some(event_id.clone()),
+            None,
+            ctx.state.bridge_config.delivery_policy,
+            ctx.state.bridge_config.delivery_quorum,
+        ),
+        event_id.clone(),
+        command,
+    )?;
+    let job = match reserved {
+        crate::core::bridge::store::BridgeJobReservation::Accepted(job) => job,
+        crate::core::bridge::store::BridgeJobReservation::Duplicate(existing) => {
+            return Ok(BridgePublishResponse {
+                deduplicated: true,
+                job: Some(existing.into()),
+                propagation: None,
+                preview: None,
+            });
+        }
+    };
+
+    let publish_settings = BridgePublishSettings::from_config(&ctx.state.bridge_config);
+    ensure_publish_relays(ctx, &job, &publish_settings).await?;
+    let execution = connect_and_publish_event(
+        &ctx.state.client,
+        &ctx.state.bridge_relay_stats,
+        &publish_settings,
+        &event,
+    )
+    .await;
+    let job = ctx
+        .state
+        .bridge_jobs
+        .complete(&job.job_id, Some(event_id), execution)
+        .map_err(|error| RpcError::Other(format!("failed to persist {command} job: {error}")))?
+        .ok_or_else(|| RpcError::Other("bridge job disappeared during completion".to_string()))?;
+
+    Ok(BridgePublishResponse {
+        deduplicated: false,
+        job: Some(job.into()),
+        propagation: None,
+        preview: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use radroots_identity::RadrootsIdentity;
+    use radroots_nostr::prelude::{
+        RadrootsNostrEvent, RadrootsNostrEventBuilder, RadrootsNostrKeys, RadrootsNostrMetadata,
+    };
+
+    use crate::app::config::{BridgeConfig, Nip46Config};
+    use crate::core::Radrootsd;
+    use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+    use super::{BridgeEventPublishRawParams, publish_raw_event};
+
+    fn ctx() -> RpcContext {
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let state = Radrootsd::new(
+            RadrootsIdentity::generate(),
+            metadata,
+            BridgeConfig {
+                enabled: true,
+                bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
+                ..BridgeConfig::default()
+            },
+            Nip46Config::default(),
+        )
+        .expect("state");
+        RpcContext::new(state, MethodRegistry::default())
+    }
+
+    fn signed_event() -> RadrootsNostrEvent {
+        RadrootsNostrEventBuilder::text_note("fresh eggs at the stand")
+            .sign_with_keys(&RadrootsNostrKeys::generate())
+            .expect("signed event")
+    }
+
+    #[tokio::test]
+    async fn publish_raw_event_accepts_presigned_event_unchanged() {
+        let ctx = ctx();
+        let event = signed_event();
+        let event_id = event.id.to_hex();
+
+        let response = publish_raw_event(
+            ctx,
+            BridgeEventPublishRawParams {
+                event,
+                idempotency_key: None,
+            },
+        )
+        .await
+        .expect("published");
+
+        assert!(!response.deduplicated);
+        let job = response.job.as_ref().expect("job");
+        assert_eq!(job.command, "bridge.event.publish_raw");
+        assert_eq!(job.event_id.as_deref(), Some(event_id.as_str()));
+        assert_eq!(job.signer_mode, "presigned_event");
+        assert_eq!(job.event_kind, 1);
+    }
+
+    #[tokio::test]
+    async fn publish_raw_event_rejects_tampered_event() {
+        let ctx = ctx();
+        let mut event = signed_event();
+        event.content = "tampered".to_string();
+
+        let err = publish_raw_event(
+            ctx.clone(),
+            BridgeEventPublishRawParams {
+                event,
+                idempotency_key: None,
+            },
+        )
+        .await
+        .expect_err("tampered event rejected");
+
+        assert!(matches!(err, RpcError::InvalidParams(_)));
+        assert!(err.to_string().contains("invalid signed event"));
+        assert_eq!(ctx.state.bridge_jobs.snapshot().retained_jobs, 0);
+    }
+
+    #[tokio::test]
+    async fn publish_raw_event_deduplicates_retries_below_relay_threshold() {
+        let ctx = ctx();
+        let event = signed_event();
+        let first = publish_raw_event(
+            ctx.clone(),
+            BridgeEventPublishRawParams {
+                event: event.clone(),
+                idempotency_key: Some("retry".to_string()),
+            },
+        )
+        .await
+        .expect("first");
+        let mut state = ctx.state.clone();
+        state.bridge_config.min_relays_for_publish = 2;
+        let outage = RpcContext::new(state, MethodRegistry::default());
+
+        let retry = publish_raw_event(
+            outage.clone(),
+            BridgeEventPublishRawParams {
+                event,
+                idempotency_key: Some("retry".to_string()),
+            },
+        )
+        .await
+        .expect("deduplicated retry");
+        assert!(retry.deduplicated);
+        assert_eq!(
+            retry.job.as_ref().expect("job").job_id,
+            first.job.as_ref().expect("job").job_id
+        );
+
+        let err = publish_raw_event(
+            outage,
+            BridgeEventPublishRawParams {
+                event: signed_event(),
+                idempotency_key: Some("fresh".to_string()),
+            },
+        )
+        .await
+        .expect_err("fresh job below threshold");
+        assert!(matches!(
+            err,
+            RpcError::InsufficientRelays {
+                required: 2,
+                connected: 0
+            }
+        ));
+    }
+}
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use radroots_nostr::prelude::{RadrootsNostrEvent, RadrootsNostrEventId, RadrootsNostrFilter};
+use serde::Deserialize;
+
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::methods::bridge::event_publish_raw::publish_presigned_event;
+use crate::transport::jsonrpc::methods::bridge::shared::{
+    BridgePublishResponse, ensure_bridge_enabled, fetch_bridge_events, normalize_idempotency_key,
+};
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+#[derive(Debug, Deserialize)]
+struct BridgeEventRebroadcastParams {
+    event_id: String,
+    #[serde(default)]
+    idempotency_key: Option<String>,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.event.rebroadcast");
+    m.register_async_method(
+        "bridge.event.rebroadcast",
+        |params, ctx, extensions| async move {
+            require_bridge_auth(&extensions)?;
+            let params: BridgeEventRebroadcastParams = params
+                .parse()
+                .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+            let response =
+                rebroadcast_event(&ctx.as_ref().clone().with_deadline(&extensions), params).await?;
+            Ok::<BridgePublishResponse, RpcError>(response)
+        },
+    )?;
+    Ok(())
+}
+
+async fn rebroadcast_event(
+    ctx: &RpcContext,
+    params: BridgeEventRebroadcastParams,
+) -> Result<BridgePublishResponse, RpcError> {
+    ensure_bridge_enabled(ctx)?;
+    let idempotency_key = normalize_idempotency_key(params.idempotency_key)?;
+    let event_id = RadrootsNostrEventId::from_hex(params.event_id.trim())
+        .map_err(|error| RpcError::InvalidParams(format!("event_id is invalid: {error}")))?;
+    ctx.require_relays().await?;
+
+    let found = fetch_bridge_events(
+        ctx,
+        RadrootsNostrFilter::new().id(event_id).limit(1),
+        Duration::from_secs(ctx.state.bridge_config.connect_timeout_secs),
+    )
+    .await
+    .map_err(|error| RpcError::Other(format!("failed to fetch event {event_id}: {error}")))?;
+    let event = select_rebroadcast_event(found, &event_id)?;
+    publish_presigned_event(ctx, "bridge.event.rebroadcast", event, idempotency_key).await
+}
+
+fn select_rebroadcast_event(
+    found: Vec<RadrootsNostrEvent>,
+    event_id: &RadrootsNostrEventId,
+) -> Result<RadrootsNostrEvent, RpcError> {
+    let event = found
+        .into_iter()
+        .find(|event| &event.id == event_id)
+        .ok_or_else(|| {
+            RpcError::InvalidParams(format!("event {event_id} was not found on relays"))
+        })?;
+    event.verify().map_err(|error| {
+        RpcError::Other(format!(
+            "relay returned an invalid copy of {event_id}: {error}"
+        ))
+    })?;
+    Ok(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use radroots_nostr::prelude::{RadrootsNostrEventBuilder, RadrootsNostrKeys};
+
+    use crate::transport::jsonrpc::RpcError;
+
+    use super::select_rebroadcast_event;
+
+    #[test]
+    fn select_rebroadcast_event_returns_fetched_event_unchanged() {
+        let keys = RadrootsNostrKeys::generate();
+        let wanted = RadrootsNostrEventBuilder::text_note("eggs")
+            .sign_with_keys(&keys)
+            .expect("wanted");
+        let other = RadrootsNostrEventBuilder::text_note("honey")
+            .sign_with_keys(&keys)
+            .expect("other");
+
+        let selected =
+            select_rebroadcast_event(vec![other, wanted.clone()], &wanted.id).expect("selected");
+
+        assert_eq!(selected, wanted);
+    }
+
+    #[test]
+    fn select_rebroadcast_event_rejects_missing_and_tampered_events() {
+        let mut event = RadrootsNostrEventBuilder::text_note("eggs")
+            .sign_with_keys(&RadrootsNostrKeys::generate())
+            .expect("event");
+        let event_id = event.id;
+
+        let missing = select_rebroadcast_event(Vec::new(), &event_id).expect_err("missing");
+        assert!(matches!(missing, RpcError::InvalidParams(_)));
+
+        event.content = "tampered".to_string();
+        let tampered = select_rebroadcast_event(vec![event], &event_id).expect_err("tampered");
+        assert!(tampered.to_string().contains("invalid copy"));
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use nostr::nips::nip19::{FromBech32, Nip19};
+use radroots_nostr::prelude::{RadrootsNostrEvent, RadrootsNostrFilter};
+use serde::{Deserialize, Serialize};
+
+use crate::core::bridge::kinds::{canonical_kind, kind_label};
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::methods::bridge::shared::{
+    ensure_bridge_enabled, fetch_bridge_events,
+};
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+const RESOLVE_MAX_REFERENCES: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct BridgeEventResolveParams {
+    references: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct BridgeResolvedEvent {
+    kind_label: Option<&'static str>,
+    event: RadrootsNostrEvent,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.event.resolve");
+    m.register_async_method(
+        "bridge.event.resolve",
+        |params, ctx, extensions| async move {
+            require_bridge_auth(&extensions)?;
+            let params: BridgeEventResolveParams = params
+                .parse()
+                .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+            resolve_events(&ctx.as_ref().clone().with_deadline(&extensions), params).await
+        },
+    )?;
+    Ok(())
+}
+
+async fn resolve_events(
+    ctx: &RpcContext,
+    params: BridgeEventResolveParams,
+) -> Result<BTreeMap<String, Option<BridgeResolvedEvent>>, RpcError> {
+    ensure_bridge_enabled(ctx)?;
+    if params.references.is_empty() {
+        return Err(RpcError::InvalidParams("missing references".to_string()));
+    }
+    if params.references.len() > RESOLVE_MAX_REFERENCES {
+        return Err(RpcError::InvalidParams(format!(
+            "references must contain at most {RESOLVE_MAX_REFERENCES} entries"
+        )));
+    }
+    let mut lookups = Vec::with_capacity(params.references.len());
+    for (index, reference) in params.references.into_iter().enumerate() {
+        let filter = reference_filter(&reference).map_err(|error| {
+            RpcError::InvalidParams(format!("references[{index}] is invalid: {error}"))
+        })?;
+        lookups.push((reference, filter));
+    }
+    ctx.require_relays().await?;
+
+    let timeout = Duration::from_secs(ctx.state.bridge_config.connect_timeout_secs);
+    let aliases = &ctx.state.bridge_config.kind_aliases;
+    let mut resolved = BTreeMap::new();
+    for (reference, filter) in lookups {
+        let event = match fetch_bridge_events(ctx, filter, timeout).await {
+            Ok(found) => newest_valid(found),
+            Err(error) => {
+                tracing::debug!("resolve fetch for {reference} failed: {error}");
+                None
+            }
+        };
+        let entry = event.map(|event| BridgeResolvedEvent {
+            kind_label: kind_label(canonical_kind(u32::from(event.kind.as_u16()), aliases)),
+            event,
+        });
+        resolved.insert(reference, entry);
+    }
+    Ok(resolved)
+}
+
+fn reference_filter(reference: &str) -> Result<RadrootsNostrFilter, String> {
+    let reference = reference.trim();
+    let reference = reference.strip_prefix("nostr:").unwrap_or(reference);
+    match Nip19::from_bech32(reference).map_err(|error| error.to_string())? {
+        Nip19::EventId(event_id) => Ok(RadrootsNostrFilter::new().id(event_id).limit(1)),
+        Nip19::Event(event) => {
+            let mut filter = RadrootsNostrFilter::new().id(event.event_id).limit(1);
+            if let Some(author) = event.author {
+                filter = filter.author(author);
+            }
+            Ok(filter)
+        }
+        Nip19::Coordinate(address) => Ok(RadrootsNostrFilter::new()
+            .kind(address.coordinate.kind)
+            .author(address.coordinate.public_key)
+            .identifier(address.coordinate.identifier.clone())
+            .limit(1)),
+        _ => Err("expected a note, nevent or naddr reference".to_string()),
+    }
+}
+
+fn newest_valid(found: Vec<RadrootsNostrEvent>) -> Option<RadrootsNostrEvent> {
+    found
+        .into_iter()
+        .filter(|event| event.verify().is_ok())
+        .max_by_key(|event| event.created_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use nostr::nips::nip01::Coordinate;
+    use nostr::nips::nip19::{Nip19Coordinate, Nip19Event, ToBech32};
+    use radroots_nostr::prelude::{
+        RadrootsNostrEventBuilder, RadrootsNostrFilter, RadrootsNostrKeys, RadrootsNostrKind,
+        RadrootsNostrTimestamp,
+    };
+
+    use super::{newest_valid, reference_filter};
+
+    #[test]
+    fn naddr_reference_filters_by_coordinate() {
+        let keys = RadrootsNostrKeys::generate();
+        let kind = RadrootsNostrKind::from(30_340);
+        let coordinate = Coordinate::new(kind, keys.public_key()).identifier("farm-1");
+        let naddr = Nip19Coordinate::new(coordinate, Vec::<nostr::RelayUrl>::new())
+            .to_bech32()
+            .expect("naddr");
+
+        let filter = reference_filter(&format!("nostr:{naddr}")).expect("filter");
+
+        assert_eq!(
+            filter,
+            RadrootsNostrFilter::new()
+                .kind(kind)
+                .author(keys.public_key())
+                .identifier("farm-1")
+                .limit(1)
+        );
+    }
+
+    #[test]
+    fn nevent_and_note_references_filter_by_id() {
+        let keys = RadrootsNostrKeys::generate();
+        let event = RadrootsNostrEventBuilder::text_note("eggs")
+            .sign_with_keys(&keys)
+            .expect("event");
+        let nevent = Nip19Event::new(event.id)
+            .author(keys.public_key())
+            .to_bech32()
+            .expect("nevent");
+        let note = event.id.to_bech32().expect("note");
+
+        assert_eq!(
+            reference_filter(&nevent).expect("nevent filter"),
+            RadrootsNostrFilter::new()
+                .id(event.id)
+                .limit(1)
+                .author(keys.public_key())
+        );
+        assert_eq!(
+            reference_filter(&note).expect("note filter"),
+            RadrootsNostrFilter::new().id(event.id).limit(1)
+        );
+        assert!(reference_filter(&keys.public_key().to_bech32().expect("npub")).is_err());
+    }
+
+    #[test]
+    fn newest_valid_prefers_latest_verified_event() {
+        let keys = RadrootsNostrKeys::generate();
+        let older = RadrootsNostrEventBuilder::text_note("old")
+            .custom_created_at(RadrootsNostrTimestamp::from(1_000))
+            .sign_with_keys(&keys)
+            .expect("older");
+        let mut tampered = RadrootsNostrEventBuilder::text_note("new")
+            .custom_created_at(RadrootsNostrTimestamp::from(2_000))
+            .sign_with_keys(&keys)
+            .expect("newer");
+        tampered.content = "tampered".to_string();
+        let older_id = older.id;
+
+        let selected = newest_valid(vec![older, tampered]).expect("selected");
+
+        assert_eq!(selected.id, older_id);
+        assert!(newest_valid(Vec::new()).is_none());
+    }
+}
@@ -1,22 +1,37 @@
 use anyhow::Result;
 use jsonrpsee::server::RpcModule;
+use serde::Deserialize;
 
 use crate::transport::jsonrpc::auth::require_bridge_auth;
-use crate::transport::jsonrpc::methods::bridge::shared::BridgeJobView;
+use crate::transport::jsonrpc::methods::bridge::shared::{BridgeJobOutput, BridgeJobView};
 use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 
+#[derive(Debug, Default, Deserialize)]
+struct BridgeJobListParams {
+    #[serde(default)]
+    with_kind_label: bool,
+}
+
 pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
     registry.track("bridge.job.list");
-    m.register_async_method("bridge.job.list", |_params, ctx, extensions| async move {
+    m.register_async_method("bridge.job.list", |params, ctx, extensions| async move {
         require_bridge_auth(&extensions)?;
+        let params: BridgeJobListParams = if params.is_object() {
+            params
+                .parse()
+                .map_err(|e| RpcError::InvalidParams(e.to_string()))?
+        } else {
+            BridgeJobListParams::default()
+        };
         let jobs = ctx
             .state
             .bridge_jobs
             .list()
             .into_iter()
             .map(BridgeJobView::from)
+            .map(|job| job.into_output(params.with_kind_label))
             .collect::<Vec<_>>();
-        Ok::<Vec<BridgeJobView>, RpcError>(jobs)
+        Ok::<Vec<BridgeJobOutput>, RpcError>(jobs)
     })?;
     Ok(())
 }
@@ -9,6 +9,8 @@ use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 #[derive(Debug, Deserialize)]
 struct BridgeJobStatusParams {
     job_id: String,
+    #[serde(default)]
+    with_kind_label: bool,
 }
 
 pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
@@ -27,6 +29,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
             .get(job_id)
             .ok_or_else(|| RpcError::Other(format!("unknown bridge job: {job_id}")))
             .map(BridgeJobView::from)
+            .map(|job| job.into_output(params.with_kind_label))
     })?;
     Ok(())
 }
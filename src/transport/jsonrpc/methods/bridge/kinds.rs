@@ -0,0 +1,15 @@
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+
+use crate::core::bridge::kinds::{KindCatalogEntry, kind_catalog};
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.kinds");
+    m.register_async_method("bridge.kinds", |_params, _ctx, extensions| async move {
+        require_bridge_auth(&extensions)?;
+        Ok::<Vec<KindCatalogEntry>, RpcError>(kind_catalog())
+    })?;
+    Ok(())
+}
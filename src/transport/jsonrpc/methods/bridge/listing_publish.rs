@@ -1,16 +1,22 @@
+use std::time::Duration;
+
 use anyhow::Result;
 use jsonrpsee::server::RpcModule;
 use radroots_events::listing::RadrootsListing;
 use radroots_events_codec::listing::encode::to_wire_parts_with_kind;
 use radroots_events_codec::wire::WireEventParts;
-use radroots_nostr::prelude::radroots_nostr_build_event;
+use radroots_nostr::prelude::{
+    RadrootsNostrFilter, radroots_nostr_build_event, radroots_nostr_parse_pubkey,
+};
 use radroots_trade::listing::publish::{
     RadrootsTradeListingPublishError, canonicalize_listing_for_seller, resolve_listing_kind,
     validate_listing_for_seller,
 };
 use serde::{Deserialize, Serialize};
+use url::Url;
 use uuid::Uuid;
 
+use crate::core::bridge::coordinate::BridgeCoordinate;
 use crate::core::bridge::publish::{
     BridgePublishSettings, connect_and_publish_event, failed_prepublish_execution,
 };
@@ -18,9 +24,11 @@ use crate::core::bridge::store::new_listing_publish_job;
 use crate::core::nip46::session::Nip46SessionAuthority;
 use crate::transport::jsonrpc::auth::require_bridge_auth;
 use crate::transport::jsonrpc::methods::bridge::shared::{
-    BridgePublishResponse, ensure_bridge_enabled, fingerprint_bridge_request,
-    normalize_idempotency_key, reserve_bridge_job, resolve_actor_bridge_signer,
-    sign_bridge_event_builder,
+    BridgePublishOptions, BridgePublishResponse, apply_bridge_publish_options,
+    ensure_bridge_enabled, ensure_publish_relays, fetch_bridge_events, fingerprint_bridge_request,
+    normalize_idempotency_key, preview_bridge_event, reserve_bridge_job,
+    resolve_actor_bridge_signer, sanitize_bridge_content, sign_bridge_event_builder,
+    verify_bridge_propagation,
 };
 use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 
@@ -35,6 +43,8 @@ struct BridgeListingPublishParams {
     signer_authority: Option<Nip46SessionAuthority>,
     #[serde(default)]
     idempotency_key: Option<String>,
+    #[serde(flatten)]
+    options: BridgePublishOptions,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -52,7 +62,8 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
             let params: BridgeListingPublishParams = params
                 .parse()
                 .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-            let response = publish_listing(ctx.as_ref().clone(), params).await?;
+            let response =
+                publish_listing(ctx.as_ref().clone().with_deadline(&extensions), params).await?;
             Ok::<BridgePublishResponse, RpcError>(response)
         },
     )?;
@@ -75,7 +86,25 @@ async fn publish_listing(
     )
     .await?;
     let signer_pubkey = signer.signer_pubkey_hex();
-    let listing = canonicalize_listing_for_seller(params.listing, signer_pubkey.as_str());
+    let mut listing = canonicalize_listing_for_seller(params.listing, signer_pubkey.as_str());
+    link_listing_references(&mut listing, signer_pubkey.as_str());
+    let coordinate = BridgeCoordinate::new(kind, signer_pubkey.clone(), listing.d_tag.clone());
+    let _coordinate_guard = ctx
+        .state
+        .bridge_coordinates
+        .acquire(coordinate.clone())
+        .await;
+    if ctx.state.bridge_config.listing_verify_references {
+        verify_listing_references(&ctx, &listing).await?;
+    }
+    validate_listing_images(
+        listing
+            .images
+            .iter()
+            .flatten()
+            .map(|image| image.url.as_str()),
+        ctx.state.bridge_config.listing_max_images,
+    )?;
     let canonical = CanonicalBridgeListingPublishRequest { kind, listing };
     let request_fingerprint =
         fingerprint_bridge_request("bridge.listing.publish", &signer, &canonical)?;
@@ -86,9 +115,18 @@ async fn publish_listing(
         signer_pubkey.as_str(),
         &parts,
     )?;
-    let builder = radroots_nostr_build_event(parts.kind, parts.content, parts.tags)
-        .map_err(|error| RpcError::Other(format!("failed to build listing event: {error}")))?;
+    let builder = radroots_nostr_build_event(
+        parts.kind,
+        sanitize_bridge_content(&ctx.state.bridge_config, parts.content)?,
+        parts.tags,
+    )
+    .map_err(|error| RpcError::Other(format!("failed to build listing event: {error}")))?;
 
+    let builder =
+        apply_bridge_publish_options(&ctx, &params.options, builder, Some(&coordinate)).await?;
+    if params.options.preview {
+        return preview_bridge_event(&ctx, &signer, builder, "bridge.listing.publish").await;
+    }
     let reserved = reserve_bridge_job(
         &ctx,
         new_listing_publish_job(
@@ -109,12 +147,15 @@ async fn publish_listing(
         crate::core::bridge::store::BridgeJobReservation::Duplicate(existing) => {
             return Ok(BridgePublishResponse {
                 deduplicated: true,
-                job: existing.into(),
+                job: Some(existing.into()),
+                propagation: None,
+                preview: None,
             });
         }
     };
 
     let publish_settings = BridgePublishSettings::from_config(&ctx.state.bridge_config);
+    ensure_publish_relays(&ctx, &job, &publish_settings).await?;
     let event =
         match sign_bridge_event_builder(&ctx, &signer, builder, "bridge.listing.publish").await {
             Ok(event) => event,
@@ -128,7 +169,13 @@ async fn publish_listing(
             }
         };
 
-    let execution = connect_and_publish_event(&ctx.state.client, &publish_settings, &event).await;
+    let execution = connect_and_publish_event(
+        &ctx.state.client,
+        &ctx.state.bridge_relay_stats,
+        &publish_settings,
+        &event,
+    )
+    .await;
     let job = ctx
         .state
         .bridge_jobs
@@ -140,9 +187,12 @@ async fn publish_listing(
         Some(validated.listing_addr.as_str())
     );
 
+    let propagation = verify_bridge_propagation(&ctx, &params.options, &job).await;
     Ok(BridgePublishResponse {
         deduplicated: false,
-        job: job.into(),
+        job: Some(job.into()),
+        propagation,
+        preview: None,
     })
 }
 
@@ -157,6 +207,85 @@ fn validate_canonical_listing_contract_for_signer(
     Ok(validated)
 }
 
+fn link_listing_references(listing: &mut RadrootsListing, signer_pubkey: &str) {
+    if let Some(resource_area) = listing.resource_area.as_mut()
+        && resource_area.pubkey.trim().is_empty()
+    {
+        resource_area.pubkey = signer_pubkey.to_string();
+    }
+    if let Some(plot) = listing.plot.as_mut()
+        && plot.pubkey.trim().is_empty()
+    {
+        plot.pubkey = signer_pubkey.to_string();
+    }
+}
+
+fn listing_references(listing: &RadrootsListing) -> Vec<(&'static str, &str, &str)> {
+    let mut references = Vec::new();
+    if let Some(resource_area) = listing.resource_area.as_ref() {
+        references.push((
+            "resource_area",
+            resource_area.pubkey.as_str(),
+            resource_area.d_tag.as_str(),
+        ));
+    }
+    if let Some(plot) = listing.plot.as_ref() {
+        references.push(("plot", plot.pubkey.as_str(), plot.d_tag.as_str()));
+    }
+    references
+}
+
+async fn verify_listing_references(
+    ctx: &RpcContext,
+    listing: &RadrootsListing,
+) -> Result<(), RpcError> {
+    let references = listing_references(listing);
+    if references.is_empty() {
+        return Ok(());
+    }
+    ctx.require_relays().await?;
+    let timeout = Duration::from_secs(ctx.state.bridge_config.connect_timeout_secs);
+    for (field, pubkey, d_tag) in references {
+        let author = radroots_nostr_parse_pubkey(pubkey).map_err(|error| {
+            RpcError::InvalidParams(format!("listing.{field}.pubkey is invalid: {error}"))
+        })?;
+        let filter = RadrootsNostrFilter::new()
+            .author(author)
+            .identifier(d_tag)
+            .limit(1);
+        let found = fetch_bridge_events(ctx, filter, timeout)
+            .await
+            .map_err(|error| {
+                RpcError::Other(format!("failed to look up listing.{field}: {error}"))
+            })?;
+        if found.is_empty() {
+            return Err(RpcError::InvalidParams(format!(
+                "listing.{field} references {pubkey}:{d_tag}, which was not found on relays"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_listing_images<'a>(
+    urls: impl IntoIterator<Item = &'a str>,
+    max_images: usize,
+) -> Result<(), RpcError> {
+    for (index, url) in urls.into_iter().enumerate() {
+        if index >= max_images {
+            return Err(RpcError::InvalidParams(format!(
+                "listing.images[{index}] exceeds the limit of {max_images} images"
+            )));
+        }
+        Url::parse(url).map_err(|error| {
+            RpcError::InvalidParams(format!(
+                "listing.images[{index}] is not a valid url: {error}"
+            ))
+        })?;
+    }
+    Ok(())
+}
+
 fn map_listing_publish_error(error: RadrootsTradeListingPublishError) -> RpcError {
     RpcError::InvalidParams(error.to_string())
 }
@@ -182,12 +311,15 @@ mod tests {
 
     use crate::app::config::{BridgeConfig, Nip46Config};
     use crate::core::Radrootsd;
+    use crate::core::bridge::coordinate::BridgeCoordinate;
     use crate::core::nip46::session::Nip46Session;
-    use crate::transport::jsonrpc::{MethodRegistry, RpcContext};
+    use crate::transport::jsonrpc::methods::bridge::shared::BridgePublishOptions;
+    use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
     use radroots_trade::listing::publish::canonicalize_listing_for_seller;
 
     use super::{
-        BridgeListingPublishParams, publish_listing, validate_canonical_listing_contract_for_signer,
+        BridgeListingPublishParams, link_listing_references, listing_references, publish_listing,
+        validate_canonical_listing_contract_for_signer, validate_listing_images,
     };
 
     #[test]
@@ -207,6 +339,118 @@ mod tests {
         assert!(err.to_string().contains("invalid listing contract"));
     }
 
+    #[test]
+    fn validate_listing_images_enforces_count_limit() {
+        let urls = ["https://a.example/1.jpg", "https://a.example/2.jpg"];
+        assert!(validate_listing_images(urls, 2).is_ok());
+        let err = validate_listing_images(urls, 1).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("listing.images[1] exceeds the limit of 1")
+        );
+    }
+
+    #[test]
+    fn validate_listing_images_reports_malformed_url_index() {
+        let err =
+            validate_listing_images(["https://a.example/1.jpg", "not a url"], 16).unwrap_err();
+        assert!(matches!(err, RpcError::InvalidParams(_)));
+        assert!(
+            err.to_string()
+                .contains("listing.images[1] is not a valid url")
+        );
+    }
+
+    #[test]
+    fn link_listing_references_fills_missing_self_owned_pubkeys() {
+        let mut listing = base_listing();
+        listing.resource_area = Some(
+            serde_json::from_value(serde_json::json!({
+                "pubkey": "",
+                "d_tag": "AAAAAAAAAAAAAAAAAAAABA",
+            }))
+            .expect("resource area ref"),
+        );
+        listing.plot = Some(
+            serde_json::from_value(serde_json::json!({
+                "pubkey": "other",
+                "d_tag": "AAAAAAAAAAAAAAAAAAAABQ",
+            }))
+            .expect("plot ref"),
+        );
+
+        link_listing_references(&mut listing, "abc123");
+
+        assert_eq!(
+            listing_references(&listing),
+            vec![
+                ("resource_area", "abc123", "AAAAAAAAAAAAAAAAAAAABA"),
+                ("plot", "other", "AAAAAAAAAAAAAAAAAAAABQ"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_listing_verifies_references_before_job_reserve() {
+        let identity = RadrootsIdentity::generate();
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let state = Radrootsd::new(
+            identity,
+            metadata,
+            BridgeConfig {
+                enabled: true,
+                bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
+                listing_verify_references: true,
+                ..BridgeConfig::default()
+            },
+            Nip46Config::default(),
+        )
+        .expect("state");
+        let ctx = RpcContext::new(state, MethodRegistry::default());
+        let session_id = insert_signer_session(&ctx, "session-1").await;
+        let mut listing = base_listing();
+        listing.plot = Some(
+            serde_json::from_value(serde_json::json!({
+                "pubkey": "",
+                "d_tag": "AAAAAAAAAAAAAAAAAAAABQ",
+            }))
+            .expect("plot ref"),
+        );
+
+        let err = publish_listing(
+            ctx.clone(),
+            BridgeListingPublishParams {
+                listing,
+                kind: None,
+                signer_session_id: Some(session_id.clone()),
+                signer_authority: None,
+                idempotency_key: Some("verify-plot".to_string()),
+                options: Default::default(),
+            },
+        )
+        .await
+        .expect_err("unverifiable plot rejected");
+        assert!(matches!(err, RpcError::NoRelays { .. }));
+        assert_eq!(ctx.state.bridge_jobs.snapshot().retained_jobs, 0);
+
+        let response = publish_listing(
+            ctx,
+            BridgeListingPublishParams {
+                listing: base_listing(),
+                kind: None,
+                signer_session_id: Some(session_id),
+                signer_authority: None,
+                idempotency_key: Some("no-references".to_string()),
+                options: Default::default(),
+            },
+        )
+        .await
+        .expect("listing without references");
+        assert!(!response.deduplicated);
+    }
+
     #[tokio::test]
     async fn publish_listing_is_job_backed_and_idempotent() {
         let identity = RadrootsIdentity::generate();
@@ -218,6 +462,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -231,12 +476,14 @@ mod tests {
             signer_session_id: Some(session_id.clone()),
             signer_authority: None,
             idempotency_key: Some("same-key".to_string()),
+            options: Default::default(),
         };
 
         let first = publish_listing(ctx.clone(), params).await.expect("first");
         assert!(!first.deduplicated);
-        assert_eq!(first.job.command, "bridge.listing.publish");
-        assert!(first.job.event_addr.is_some());
+        let first_job = first.job.as_ref().expect("job");
+        assert_eq!(first_job.command, "bridge.listing.publish");
+        assert!(first_job.event_addr.is_some());
 
         let second = publish_listing(
             ctx,
@@ -246,12 +493,59 @@ mod tests {
                 signer_session_id: Some(session_id),
                 signer_authority: None,
                 idempotency_key: Some("same-key".to_string()),
+                options: Default::default(),
             },
         )
         .await
         .expect("second");
         assert!(second.deduplicated);
-        assert_eq!(second.job.job_id, first.job.job_id);
+        assert_eq!(second.job.as_ref().expect("job").job_id, first_job.job_id);
+    }
+
+    #[tokio::test]
+    async fn publish_listing_preview_returns_signed_event_without_job() {
+        let identity = RadrootsIdentity::generate();
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let state = Radrootsd::new(
+            identity,
+            metadata,
+            BridgeConfig {
+                enabled: true,
+                bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
+                ..BridgeConfig::default()
+            },
+            Nip46Config::default(),
+        )
+        .expect("state");
+        let ctx = RpcContext::new(state, MethodRegistry::default());
+        let session_id = insert_signer_session(&ctx, "session-1").await;
+
+        let response = publish_listing(
+            ctx.clone(),
+            BridgeListingPublishParams {
+                listing: base_listing(),
+                kind: None,
+                signer_session_id: Some(session_id),
+                signer_authority: None,
+                idempotency_key: Some("preview".to_string()),
+                options: BridgePublishOptions {
+                    preview: true,
+                    ..Default::default()
+                },
+            },
+        )
+        .await
+        .expect("preview");
+
+        let event = response.preview.expect("previewed event");
+        assert!(event.verify().is_ok());
+        assert_eq!(u32::from(event.kind.as_u16()), KIND_LISTING);
+        assert!(!event.tags.is_empty());
+        assert!(response.job.is_none());
+        assert_eq!(ctx.state.bridge_jobs.snapshot().retained_jobs, 0);
+        assert!(ctx.state.bridge_relay_stats.snapshot().relays.is_empty());
     }
 
     #[tokio::test]
@@ -265,6 +559,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -283,6 +578,7 @@ mod tests {
                 signer_session_id: Some(session_id),
                 signer_authority: None,
                 idempotency_key: Some("bad-listing".to_string()),
+                options: Default::default(),
             },
         )
         .await
@@ -302,6 +598,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -318,16 +615,16 @@ mod tests {
                 signer_session_id: Some(session_id),
                 signer_authority: None,
                 idempotency_key: Some("draft-kind".to_string()),
+                options: Default::default(),
             },
         )
         .await
         .expect("draft listing");
 
-        assert_eq!(response.job.event_kind, KIND_LISTING_DRAFT);
+        let job = response.job.as_ref().expect("job");
+        assert_eq!(job.event_kind, KIND_LISTING_DRAFT);
         assert!(
-            response
-                .job
-                .event_addr
+            job.event_addr
                 .as_deref()
                 .is_some_and(|addr| addr.starts_with("30403:"))
         );
@@ -344,6 +641,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -359,6 +657,7 @@ mod tests {
                 signer_session_id: None,
                 signer_authority: None,
                 idempotency_key: Some("missing-session".to_string()),
+                options: Default::default(),
             },
         )
         .await
@@ -366,6 +665,73 @@ mod tests {
         assert!(err.to_string().contains("requires signer_session_id"));
     }
 
+    #[tokio::test]
+    async fn publish_listing_serializes_publishes_to_one_coordinate() {
+        let identity = RadrootsIdentity::generate();
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let state = Radrootsd::new(
+            identity,
+            metadata,
+            BridgeConfig {
+                enabled: true,
+                bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
+                ..BridgeConfig::default()
+            },
+            Nip46Config::default(),
+        )
+        .expect("state");
+        let ctx = RpcContext::new(state, MethodRegistry::default());
+        let session_id = insert_signer_session(&ctx, "session-1").await;
+        let signer_pubkey = ctx
+            .state
+            .nip46_sessions
+            .get(&session_id)
+            .await
+            .expect("session")
+            .remote_signer_pubkey
+            .to_hex();
+        let held = ctx
+            .state
+            .bridge_coordinates
+            .acquire(BridgeCoordinate::new(
+                KIND_LISTING,
+                signer_pubkey,
+                base_listing().d_tag,
+            ))
+            .await;
+
+        let publishes = ["first", "second"].map(|key| {
+            let ctx = ctx.clone();
+            let session_id = session_id.clone();
+            tokio::spawn(async move {
+                publish_listing(
+                    ctx,
+                    BridgeListingPublishParams {
+                        listing: base_listing(),
+                        kind: None,
+                        signer_session_id: Some(session_id),
+                        signer_authority: None,
+                        idempotency_key: Some(key.to_string()),
+                        options: Default::default(),
+                    },
+                )
+                .await
+            })
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(publishes.iter().all(|publish| !publish.is_finished()));
+        assert_eq!(ctx.state.bridge_jobs.snapshot().retained_jobs, 0);
+
+        drop(held);
+        for publish in publishes {
+            let response = publish.await.expect("publish task").expect("published");
+            assert!(!response.deduplicated);
+        }
+        assert_eq!(ctx.state.bridge_jobs.snapshot().retained_jobs, 2);
+    }
+
     async fn insert_signer_session(ctx: &RpcContext, session_id: &str) -> String {
         let signer_keys = RadrootsNostrKeys::generate();
         let signer_pubkey = signer_keys.public_key().to_hex();
@@ -389,6 +755,7 @@ mod tests {
                 url: None,
                 image: None,
                 expires_at: Some(Instant::now() + std::time::Duration::from_secs(60)),
+                last_used_at: Instant::now(),
                 auth_required: false,
                 authorized: true,
                 auth_url: None,
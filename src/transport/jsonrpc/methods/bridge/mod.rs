@@ -3,25 +3,47 @@ use jsonrpsee::server::RpcModule;
 
 use crate::transport::jsonrpc::{MethodRegistry, RpcContext};
 
+mod config;
+mod event_id;
+mod event_publish_raw;
+mod event_rebroadcast;
+mod event_resolve;
 mod farm_publish;
 mod job_list;
 mod job_status;
+mod kinds;
 mod listing_publish;
 mod order_request;
+mod order_transition;
 mod profile_publish;
 mod public_trade;
+mod publish_report;
+mod ready_wait;
+mod relay_errors;
+mod relay_stats;
 mod shared;
 mod status;
 
 pub fn module(ctx: RpcContext, registry: MethodRegistry) -> Result<RpcModule<RpcContext>> {
     let mut m = RpcModule::new(ctx);
     status::register(&mut m, &registry)?;
+    config::register(&mut m, &registry)?;
     job_list::register(&mut m, &registry)?;
     job_status::register(&mut m, &registry)?;
+    kinds::register(&mut m, &registry)?;
     profile_publish::register(&mut m, &registry)?;
     farm_publish::register(&mut m, &registry)?;
     listing_publish::register(&mut m, &registry)?;
+    event_id::register(&mut m, &registry)?;
+    event_publish_raw::register(&mut m, &registry)?;
+    event_rebroadcast::register(&mut m, &registry)?;
+    event_resolve::register(&mut m, &registry)?;
     order_request::register(&mut m, &registry)?;
+    order_transition::register(&mut m, &registry)?;
     public_trade::register(&mut m, &registry)?;
+    publish_report::register(&mut m, &registry)?;
+    ready_wait::register(&mut m, &registry)?;
+    relay_stats::register(&mut m, &registry)?;
+    relay_errors::register(&mut m, &registry)?;
     Ok(m)
 }
@@ -16,9 +16,11 @@ use crate::core::bridge::store::new_order_request_job;
 use crate::core::nip46::session::Nip46SessionAuthority;
 use crate::transport::jsonrpc::auth::require_bridge_auth;
 use crate::transport::jsonrpc::methods::bridge::shared::{
-    BridgePublishResponse, ensure_bridge_enabled, fingerprint_bridge_request,
-    normalize_idempotency_key, reserve_bridge_job, resolve_actor_bridge_signer,
-    sign_bridge_event_builder,
+    BridgePublishOptions, BridgePublishResponse, apply_bridge_publish_options,
+    ensure_bridge_enabled, ensure_publish_relays, fingerprint_bridge_request,
+    normalize_idempotency_key, preview_bridge_event, reserve_bridge_job,
+    resolve_actor_bridge_signer, sanitize_bridge_content, sign_bridge_event_builder,
+    verify_bridge_propagation,
 };
 use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 
@@ -32,6 +34,8 @@ struct BridgeOrderRequestParams {
     signer_authority: Option<Nip46SessionAuthority>,
     #[serde(default)]
     idempotency_key: Option<String>,
+    #[serde(flatten)]
+    options: BridgePublishOptions,
 }
 
 #[derive(Serialize)]
@@ -49,7 +53,9 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
             let params: BridgeOrderRequestParams = params
                 .parse()
                 .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-            let response = publish_order_request(ctx.as_ref().clone(), params).await?;
+            let response =
+                publish_order_request(ctx.as_ref().clone().with_deadline(&extensions), params)
+                    .await?;
             Ok::<BridgePublishResponse, RpcError>(response)
         },
     )?;
@@ -92,10 +98,16 @@ async fn publish_order_request(
         active_trade_order_request_event_build(&listing_event, &order).map_err(|error| {
             RpcError::Other(format!("failed to build order request event: {error}"))
         })?;
-    let builder =
-        radroots_nostr_build_event(built.kind, built.content, built.tags).map_err(|error| {
-            RpcError::Other(format!("failed to build order request event: {error}"))
-        })?;
+    let builder = radroots_nostr_build_event(
+        built.kind,
+        sanitize_bridge_content(&ctx.state.bridge_config, built.content)?,
+        built.tags,
+    )
+    .map_err(|error| RpcError::Other(format!("failed to build order request event: {error}")))?;
+    let builder = apply_bridge_publish_options(&ctx, &params.options, builder, None).await?;
+    if params.options.preview {
+        return preview_bridge_event(&ctx, &signer, builder, "bridge.order.request").await;
+    }
 
     let reserved = reserve_bridge_job(
         &ctx,
@@ -117,12 +129,15 @@ async fn publish_order_request(
         crate::core::bridge::store::BridgeJobReservation::Duplicate(existing) => {
             return Ok(BridgePublishResponse {
                 deduplicated: true,
-                job: existing.into(),
+                job: Some(existing.into()),
+                propagation: None,
+                preview: None,
             });
         }
     };
 
     let publish_settings = BridgePublishSettings::from_config(&ctx.state.bridge_config);
+    ensure_publish_relays(&ctx, &job, &publish_settings).await?;
     let event =
         match sign_bridge_event_builder(&ctx, &signer, builder, "bridge.order.request").await {
             Ok(event) => event,
@@ -136,7 +151,13 @@ async fn publish_order_request(
             }
         };
 
-    let execution = connect_and_publish_event(&ctx.state.client, &publish_settings, &event).await;
+    let execution = connect_and_publish_event(
+        &ctx.state.client,
+        &ctx.state.bridge_relay_stats,
+        &publish_settings,
+        &event,
+    )
+    .await;
     let job = ctx
         .state
         .bridge_jobs
@@ -144,9 +165,12 @@ async fn publish_order_request(
         .map_err(|error| RpcError::Other(format!("failed to persist bridge order job: {error}")))?
         .ok_or_else(|| RpcError::Other("bridge job disappeared during completion".to_string()))?;
 
+    let propagation = verify_bridge_propagation(&ctx, &params.options, &job).await;
     Ok(BridgePublishResponse {
         deduplicated: false,
-        job: job.into(),
+        job: Some(job.into()),
+        propagation,
+        preview: None,
     })
 }
 
@@ -221,6 +245,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -234,14 +259,16 @@ mod tests {
             signer_session_id: Some(session_id.clone()),
             signer_authority: None,
             idempotency_key: Some("same-key".to_string()),
+            options: Default::default(),
         };
 
         let first = publish_order_request(ctx.clone(), params)
             .await
             .expect("first");
         assert!(!first.deduplicated);
-        assert_eq!(first.job.command, "bridge.order.request");
-        assert_eq!(first.job.event_addr.as_deref(), Some(base_listing_addr()));
+        let first_job = first.job.as_ref().expect("job");
+        assert_eq!(first_job.command, "bridge.order.request");
+        assert_eq!(first_job.event_addr.as_deref(), Some(base_listing_addr()));
 
         let second = publish_order_request(
             ctx,
@@ -251,12 +278,13 @@ mod tests {
                 signer_session_id: Some(session_id),
                 signer_authority: None,
                 idempotency_key: Some("same-key".to_string()),
+                options: Default::default(),
             },
         )
         .await
         .expect("second");
         assert!(second.deduplicated);
-        assert_eq!(second.job.job_id, first.job.job_id);
+        assert_eq!(second.job.as_ref().expect("job").job_id, first_job.job_id);
     }
 
     #[tokio::test]
@@ -270,6 +298,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -285,6 +314,7 @@ mod tests {
                 signer_session_id: Some(session_id.clone()),
                 signer_authority: None,
                 idempotency_key: Some("same-key".to_string()),
+                options: Default::default(),
             },
         )
         .await
@@ -300,6 +330,7 @@ mod tests {
                 signer_session_id: Some(session_id),
                 signer_authority: None,
                 idempotency_key: Some("same-key".to_string()),
+                options: Default::default(),
             },
         )
         .await
@@ -318,6 +349,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -333,6 +365,7 @@ mod tests {
                 signer_session_id: None,
                 signer_authority: None,
                 idempotency_key: Some("missing-session".to_string()),
+                options: Default::default(),
             },
         )
         .await
@@ -363,6 +396,7 @@ mod tests {
                 url: None,
                 image: None,
                 expires_at: Some(Instant::now() + std::time::Duration::from_secs(60)),
+                last_used_at: Instant::now(),
                 auth_required: false,
                 authorized: true,
                 auth_url: None,
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use serde::{Deserialize, Serialize};
+
+use crate::core::bridge::order_lifecycle::{BridgeOrderStep, validate_order_transition};
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+#[derive(Debug, Deserialize)]
+struct BridgeOrderTransitionParams {
+    #[serde(default)]
+    history: Vec<BridgeOrderStep>,
+    next: BridgeOrderStep,
+}
+
+#[derive(Debug, Serialize)]
+struct BridgeOrderTransitionResponse {
+    valid: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.order.validate_transition");
+    m.register_async_method(
+        "bridge.order.validate_transition",
+        |params, _ctx, extensions| async move {
+            require_bridge_auth(&extensions)?;
+            let params: BridgeOrderTransitionParams = params
+                .parse()
+                .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+            let response = match validate_order_transition(&params.history, params.next) {
+                Ok(()) => BridgeOrderTransitionResponse {
+                    valid: true,
+                    reason: None,
+                },
+                Err(reason) => BridgeOrderTransitionResponse {
+                    valid: false,
+                    reason: Some(reason),
+                },
+            };
+            Ok::<BridgeOrderTransitionResponse, RpcError>(response)
+        },
+    )?;
+    Ok(())
+}
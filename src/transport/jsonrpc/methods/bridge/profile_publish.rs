@@ -9,6 +9,7 @@ use radroots_nostr::prelude::radroots_nostr_build_event;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::core::bridge::coordinate::BridgeCoordinate;
 use crate::core::bridge::publish::{
     BridgePublishSettings, connect_and_publish_event, failed_prepublish_execution,
 };
@@ -16,9 +17,11 @@ use crate::core::bridge::store::new_publish_job;
 use crate::core::nip46::session::Nip46SessionAuthority;
 use crate::transport::jsonrpc::auth::require_bridge_auth;
 use crate::transport::jsonrpc::methods::bridge::shared::{
-    BridgePublishResponse, ensure_bridge_enabled, fingerprint_bridge_request,
-    normalize_idempotency_key, reserve_bridge_job, resolve_actor_bridge_signer,
-    sign_bridge_event_builder,
+    BridgePublishOptions, BridgePublishResponse, apply_bridge_publish_options,
+    ensure_bridge_enabled, ensure_publish_relays, fingerprint_bridge_request,
+    normalize_idempotency_key, preview_bridge_event, reserve_bridge_job,
+    resolve_actor_bridge_signer, sanitize_bridge_content, sign_bridge_event_builder,
+    verify_bridge_propagation,
 };
 use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 
@@ -33,6 +36,8 @@ struct BridgeProfilePublishParams {
     signer_authority: Option<Nip46SessionAuthority>,
     #[serde(default)]
     idempotency_key: Option<String>,
+    #[serde(flatten)]
+    options: BridgePublishOptions,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -50,7 +55,8 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
             let params: BridgeProfilePublishParams = params
                 .parse()
                 .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-            let response = publish_profile(ctx.as_ref().clone(), params).await?;
+            let response =
+                publish_profile(ctx.as_ref().clone().with_deadline(&extensions), params).await?;
             Ok::<BridgePublishResponse, RpcError>(response)
         },
     )?;
@@ -79,9 +85,24 @@ async fn publish_profile(
         fingerprint_bridge_request("bridge.profile.publish", &signer, &canonical)?;
     let parts = to_wire_parts_with_profile_type(&canonical.profile, canonical.profile_type)
         .map_err(|error| RpcError::InvalidParams(format!("invalid profile contract: {error}")))?;
-    let builder = radroots_nostr_build_event(parts.kind, parts.content, parts.tags)
-        .map_err(|error| RpcError::Other(format!("failed to build profile event: {error}")))?;
+    let builder = radroots_nostr_build_event(
+        parts.kind,
+        sanitize_bridge_content(&ctx.state.bridge_config, parts.content)?,
+        parts.tags,
+    )
+    .map_err(|error| RpcError::Other(format!("failed to build profile event: {error}")))?;
 
+    let coordinate = BridgeCoordinate::new(parts.kind, signer.signer_pubkey_hex(), "");
+    let _coordinate_guard = ctx
+        .state
+        .bridge_coordinates
+        .acquire(coordinate.clone())
+        .await;
+    let builder =
+        apply_bridge_publish_options(&ctx, &params.options, builder, Some(&coordinate)).await?;
+    if params.options.preview {
+        return preview_bridge_event(&ctx, &signer, builder, "bridge.profile.publish").await;
+    }
     let reserved = reserve_bridge_job(
         &ctx,
         new_publish_job(
@@ -103,12 +124,15 @@ async fn publish_profile(
         crate::core::bridge::store::BridgeJobReservation::Duplicate(existing) => {
             return Ok(BridgePublishResponse {
                 deduplicated: true,
-                job: existing.into(),
+                job: Some(existing.into()),
+                propagation: None,
+                preview: None,
             });
         }
     };
 
     let publish_settings = BridgePublishSettings::from_config(&ctx.state.bridge_config);
+    ensure_publish_relays(&ctx, &job, &publish_settings).await?;
     let event =
         match sign_bridge_event_builder(&ctx, &signer, builder, "bridge.profile.publish").await {
             Ok(event) => event,
@@ -122,7 +146,13 @@ async fn publish_profile(
             }
         };
 
-    let execution = connect_and_publish_event(&ctx.state.client, &publish_settings, &event).await;
+    let execution = connect_and_publish_event(
+        &ctx.state.client,
+        &ctx.state.bridge_relay_stats,
+        &publish_settings,
+        &event,
+    )
+    .await;
     let job = ctx
         .state
         .bridge_jobs
@@ -130,8 +160,11 @@ async fn publish_profile(
         .map_err(|error| RpcError::Other(format!("failed to persist bridge profile job: {error}")))?
         .ok_or_else(|| RpcError::Other("bridge job disappeared during completion".to_string()))?;
 
+    let propagation = verify_bridge_propagation(&ctx, &params.options, &job).await;
     Ok(BridgePublishResponse {
         deduplicated: false,
-        job: job.into(),
+        job: Some(job.into()),
+        propagation,
+        preview: None,
     })
 }
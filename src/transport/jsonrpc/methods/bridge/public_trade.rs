@@ -25,9 +25,10 @@ use crate::core::bridge::publish::{
 use crate::core::bridge::store::new_publish_job;
 use crate::transport::jsonrpc::auth::require_bridge_auth;
 use crate::transport::jsonrpc::methods::bridge::shared::{
-    BridgePublishResponse, ensure_bridge_enabled, fingerprint_bridge_request,
-    normalize_idempotency_key, reserve_bridge_job, resolve_bridge_signer,
-    sign_bridge_event_builder,
+    BridgePublishOptions, BridgePublishResponse, apply_bridge_publish_options,
+    ensure_bridge_enabled, ensure_publish_relays, fingerprint_bridge_request,
+    normalize_idempotency_key, preview_bridge_event, reserve_bridge_job, resolve_bridge_signer,
+    sanitize_bridge_content, sign_bridge_event_builder, verify_bridge_propagation,
 };
 use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 
@@ -47,6 +48,8 @@ struct BridgePublicTradeParams<T> {
     signer_session_id: Option<String>,
     #[serde(default)]
     idempotency_key: Option<String>,
+    #[serde(flatten)]
+    options: BridgePublishOptions,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -175,7 +178,7 @@ where
             .parse()
             .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
         let response = publish_public_trade(
-            ctx.as_ref().clone(),
+            ctx.as_ref().clone().with_deadline(&extensions),
             method_name,
             message_type,
             params,
@@ -200,6 +203,7 @@ where
     ensure_bridge_enabled(&ctx)?;
 
     let idempotency_key = normalize_idempotency_key(params.idempotency_key.clone())?;
+    let options = params.options.clone();
     let signer = resolve_bridge_signer(
         &ctx,
         params.signer_session_id.as_deref(),
@@ -227,8 +231,16 @@ where
         &payload,
     )
     .map_err(|error| RpcError::InvalidParams(format!("invalid {command} envelope: {error}")))?;
-    let builder = radroots_nostr_build_event(built.kind, built.content, built.tags)
-        .map_err(|error| RpcError::Other(format!("failed to build {command} event: {error}")))?;
+    let builder = radroots_nostr_build_event(
+        built.kind,
+        sanitize_bridge_content(&ctx.state.bridge_config, built.content)?,
+        built.tags,
+    )
+    .map_err(|error| RpcError::Other(format!("failed to build {command} event: {error}")))?;
+    let builder = apply_bridge_publish_options(&ctx, &options, builder, None).await?;
+    if options.preview {
+        return preview_bridge_event(&ctx, &signer, builder, command).await;
+    }
 
     let reserved = reserve_bridge_job(
         &ctx,
@@ -251,12 +263,15 @@ where
         crate::core::bridge::store::BridgeJobReservation::Duplicate(existing) => {
             return Ok(BridgePublishResponse {
                 deduplicated: true,
-                job: existing.into(),
+                job: Some(existing.into()),
+                propagation: None,
+                preview: None,
             });
         }
     };
 
     let publish_settings = BridgePublishSettings::from_config(&ctx.state.bridge_config);
+    ensure_publish_relays(&ctx, &job, &publish_settings).await?;
     let event = match sign_bridge_event_builder(&ctx, &signer, builder, command).await {
         Ok(event) => event,
         Err(error) => {
@@ -269,7 +284,13 @@ where
         }
     };
 
-    let execution = connect_and_publish_event(&ctx.state.client, &publish_settings, &event).await;
+    let execution = connect_and_publish_event(
+        &ctx.state.client,
+        &ctx.state.bridge_relay_stats,
+        &publish_settings,
+        &event,
+    )
+    .await;
     let job = ctx
         .state
         .bridge_jobs
@@ -277,9 +298,12 @@ where
         .map_err(|error| RpcError::Other(format!("failed to persist {command} job: {error}")))?
         .ok_or_else(|| RpcError::Other("bridge job disappeared during completion".to_string()))?;
 
+    let propagation = verify_bridge_propagation(&ctx, &options, &job).await;
     Ok(BridgePublishResponse {
         deduplicated: false,
-        job: job.into(),
+        job: Some(job.into()),
+        propagation,
+        preview: None,
     })
 }
 
@@ -485,6 +509,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -504,6 +529,7 @@ mod tests {
             },
             signer_session_id: None,
             idempotency_key: Some("same-key".to_string()),
+            options: Default::default(),
         };
 
         let first = publish_public_trade(
@@ -516,16 +542,17 @@ mod tests {
         .await
         .expect("first");
         assert!(!first.deduplicated);
-        assert_eq!(first.job.command, "bridge.order.response");
+        let first_job = first.job.as_ref().expect("job");
+        assert_eq!(first_job.command, "bridge.order.response");
         assert_eq!(
-            first.job.event_kind,
+            first_job.event_kind,
             TradeListingMessageType::OrderResponse.kind()
         );
         assert_eq!(
-            first.job.event_addr.as_deref(),
+            first_job.event_addr.as_deref(),
             Some(base_listing_addr(&seller_pubkey).as_str())
         );
-        assert_eq!(first.job.signer_mode, "embedded_service_identity");
+        assert_eq!(first_job.signer_mode, "embedded_service_identity");
 
         let second = publish_public_trade(
             ctx,
@@ -537,7 +564,7 @@ mod tests {
         .await
         .expect("second");
         assert!(second.deduplicated);
-        assert_eq!(second.job.job_id, first.job.job_id);
+        assert_eq!(second.job.as_ref().expect("job").job_id, first_job.job_id);
     }
 
     #[tokio::test]
@@ -562,6 +589,7 @@ mod tests {
                 },
                 signer_session_id: None,
                 idempotency_key: None,
+                options: Default::default(),
             },
             TradeListingMessagePayload::DiscountRequest,
         )
@@ -582,6 +610,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -605,6 +634,7 @@ mod tests {
                 },
                 signer_session_id: None,
                 idempotency_key: None,
+                options: Default::default(),
             },
             TradeListingMessagePayload::OrderResponse,
         )
@@ -625,6 +655,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -647,6 +678,7 @@ mod tests {
                 },
                 signer_session_id: None,
                 idempotency_key: None,
+                options: Default::default(),
             },
             TradeListingMessagePayload::Question,
         )
@@ -675,6 +707,7 @@ mod tests {
                 },
                 signer_session_id: None,
                 idempotency_key: None,
+                options: Default::default(),
             },
             TradeListingMessagePayload::OrderRevisionAccept,
         )
@@ -698,6 +731,7 @@ mod tests {
             BridgeConfig {
                 enabled: true,
                 bearer_token: Some("secret".to_string()),
+                min_relays_for_publish: 0,
                 ..BridgeConfig::default()
             },
             Nip46Config::default(),
@@ -0,0 +1,307 @@
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use radroots_nostr::prelude::{RadrootsNostrEventId, RadrootsNostrFilter, RadrootsNostrRelayUrl};
+use serde::{Deserialize, Serialize};
+
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::methods::bridge::shared::ensure_bridge_enabled;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+#[derive(Debug, Deserialize)]
+struct BridgePublishReportParams {
+    event_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BridgePublishReportResponse {
+    relay_count: usize,
+    confirmed_count: usize,
+    events: Vec<BridgePublishReportEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(super) struct BridgePublishReportEntry {
+    event_id: String,
+    confirmed: bool,
+    relay_count: usize,
+    relays: Vec<String>,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.publish.report");
+    m.register_async_method(
+        "bridge.publish.report",
+        |params, ctx, extensions| async move {
+            require_bridge_auth(&extensions)?;
+            let params: BridgePublishReportParams = params
+                .parse()
+                .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+            publish_report(&ctx.as_ref().clone().with_deadline(&extensions), params).await
+        },
+    )?;
+    Ok(())
+}
+
+async fn publish_report(
+    ctx: &RpcContext,
+    params: BridgePublishReportParams,
+) -> Result<BridgePublishReportResponse, RpcError> {
+    ensure_bridge_enabled(ctx)?;
+    if params.event_ids.is_empty() {
+        return Err(RpcError::InvalidParams("missing event_ids".to_string()));
+    }
+    let mut event_ids = Vec::with_capacity(params.event_ids.len());
+    for (index, event_id) in params.event_ids.iter().enumerate() {
+        let event_id = RadrootsNostrEventId::from_hex(event_id.trim()).map_err(|error| {
+            RpcError::InvalidParams(format!("event_ids[{index}] is invalid: {error}"))
+        })?;
+        event_ids.push(event_id);
+    }
+    ctx.require_relays().await?;
+
+    let timeout = ctx.fetch_timeout(Duration::from_secs(
+        ctx.state.bridge_config.connect_timeout_secs,
+    ));
+    let relays = ctx
+        .state
+        .client
+        .relays()
+        .await
+        .into_keys()
+        .collect::<Vec<_>>();
+    let filter = RadrootsNostrFilter::new().ids(event_ids.clone());
+    let relay_hits = fetch_back_within(relays, timeout, |url, remaining| {
+        fetch_event_ids_from(ctx, url, filter.clone(), remaining)
+    })
+    .await;
+
+    let requested = event_ids
+        .iter()
+        .map(RadrootsNostrEventId::to_hex)
+        .collect::<Vec<_>>();
+    let events = count_publish_confirmations(&requested, &relay_hits);
+    Ok(BridgePublishReportResponse {
+        relay_count: relay_hits.len(),
+        confirmed_count: events.iter().filter(|entry| entry.confirmed).count(),
+        events,
+    })
+}
+
+pub(super) async fn verify_event_propagation(
+    ctx: &RpcContext,
+    event_id: RadrootsNostrEventId,
+) -> BridgePublishReportEntry {
+    let timeout = ctx.fetch_timeout(Duration::from_secs(
+        ctx.state.bridge_config.connect_timeout_secs,
+    ));
+    let relays = ctx
+        .state
+        .client
+        .relays()
+        .await
+        .into_keys()
+        .collect::<Vec<_>>();
+    let relay_hits = fetch_back_within(relays, timeout, |url, remaining| {
+        fetch_event_ids_from(ctx, url, RadrootsNostrFilter::new().id(event_id), remaining)
+    })
+    .await;
+    let event_id = event_id.to_hex();
+    count_publish_confirmations(std::slice::from_ref(&event_id), &relay_hits)
+        .pop()
+        .unwrap_or(BridgePublishReportEntry {
+            event_id,
+            confirmed: false,
+            relay_count: 0,
+            relays: Vec::new(),
+        })
+}
+
+async fn fetch_event_ids_from(
+    ctx: &RpcContext,
+    url: RadrootsNostrRelayUrl,
+    filter: RadrootsNostrFilter,
+    timeout: Duration,
+) -> Vec<String> {
+    match ctx
+        .state
+        .client
+        .fetch_events_from([url.clone()], filter, timeout)
+        .await
+    {
+        Ok(events) => ctx
+            .state
+            .bridge_relay_stats
+            .retain_admitted(events, ctx.state.bridge_config.max_event_bytes)
+            .into_iter()
+            .map(|event| event.id.to_hex())
+            .collect(),
+        Err(error) => {
+            tracing::debug!("event fetch from {url} failed: {error}");
+            Vec::new()
+        }
+    }
+}
+
+// Relays are asked one at a time; any left once the budget is spent count as misses.
+async fn fetch_back_within<U, F, Fut>(
+    relays: Vec<U>,
+    budget: Duration,
+    mut fetch: F,
+) -> Vec<(String, Vec<String>)>
+where
+    U: ToString,
+    F: FnMut(U, Duration) -> Fut,
+    Fut: Future<Output = Vec<String>>,
+{
+    let deadline = tokio::time::Instant::now() + budget;
+    let mut relay_hits = Vec::with_capacity(relays.len());
+    for url in relays {
+        let relay = url.to_string();
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        let found = if remaining.is_zero() {
+            Vec::new()
+        } else {
+            tokio::time::timeout(remaining, fetch(url, remaining))
+                .await
+                .unwrap_or_default()
+        };
+        relay_hits.push((relay, found));
+    }
+    relay_hits
+}
+
+fn count_publish_confirmations(
+    event_ids: &[String],
+    relay_hits: &[(String, Vec<String>)],
+) -> Vec<BridgePublishReportEntry> {
+    let mut seen_on: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for (relay, found) in relay_hits {
+        for event_id in found {
+            seen_on
+                .entry(event_id.as_str())
+                .or_default()
+                .insert(relay.as_str());
+        }
+    }
+    event_ids
+        .iter()
+        .map(|event_id| {
+            let relays = seen_on
+                .get(event_id.as_str())
+                .map(|relays| relays.iter().map(|relay| relay.to_string()).collect())
+                .unwrap_or_default();
+            BridgePublishReportEntry {
+                event_id: event_id.clone(),
+                confirmed: !relays.is_empty(),
+                relay_count: relays.len(),
+                relays,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{count_publish_confirmations, fetch_back_within};
+
+    #[test]
+    fn count_publish_confirmations_counts_relays_per_event() {
+        let event_ids = vec!["aa".to_string(), "bb".to_string(), "cc".to_string()];
+        let relay_hits = vec![
+            (
+                "wss://relay-a.example.com".to_string(),
+                vec!["aa".to_string(), "bb".to_string()],
+            ),
+            (
+                "wss://relay-b.example.com".to_string(),
+                vec!["aa".to_string()],
+            ),
+            ("wss://relay-c.example.com".to_string(), Vec::new()),
+        ];
+
+        let entries = count_publish_confirmations(&event_ids, &relay_hits);
+
+        assert_eq!(entries.len(), 3);
+        assert!(entries[0].confirmed);
+        assert_eq!(entries[0].relay_count, 2);
+        assert_eq!(
+            entries[0].relays,
+            vec![
+                "wss://relay-a.example.com".to_string(),
+                "wss://relay-b.example.com".to_string()
+            ]
+        );
+        assert!(entries[1].confirmed);
+        assert_eq!(entries[1].relay_count, 1);
+        assert!(!entries[2].confirmed);
+        assert_eq!(entries[2].relay_count, 0);
+        assert!(entries[2].relays.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fetch_back_counts_relays_that_return_the_event() {
+        let relays = vec![
+            "wss://relay-a.example.com",
+            "wss://relay-b.example.com",
+            "wss://relay-c.example.com",
+        ];
+        let relay_hits = fetch_back_within(relays, Duration::from_secs(1), |url, _| async move {
+            if url == "wss://relay-b.example.com" {
+                Vec::new()
+            } else {
+                vec!["aa".to_string()]
+            }
+        })
+        .await;
+
+        let entries = count_publish_confirmations(&["aa".to_string()], &relay_hits);
+
+        assert_eq!(relay_hits.len(), 3);
+        assert!(entries[0].confirmed);
+        assert_eq!(entries[0].relay_count, 2);
+        assert_eq!(
+            entries[0].relays,
+            vec![
+                "wss://relay-a.example.com".to_string(),
+                "wss://relay-c.example.com".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_back_treats_relays_past_the_budget_as_misses() {
+        let relay_hits = fetch_back_within(
+            vec!["wss://relay-a.example.com"],
+            Duration::from_millis(10),
+            |_, _| async {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                vec!["aa".to_string()]
+            },
+        )
+        .await;
+
+        assert_eq!(
+            relay_hits,
+            vec![("wss://relay-a.example.com".to_string(), Vec::new())]
+        );
+    }
+
+    #[test]
+    fn count_publish_confirmations_ignores_unrequested_ids() {
+        let entries = count_publish_confirmations(
+            &["aa".to_string()],
+            &[(
+                "wss://relay-a.example.com".to_string(),
+                vec!["zz".to_string()],
+            )],
+        );
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].confirmed);
+    }
+}
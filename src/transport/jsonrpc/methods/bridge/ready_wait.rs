@@ -0,0 +1,139 @@
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use serde::{Deserialize, Serialize};
+
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+const READY_WAIT_MAX_TIMEOUT_SECS: u64 = 300;
+const READY_WAIT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Deserialize)]
+struct BridgeReadyWaitParams {
+    timeout_secs: u64,
+    #[serde(default)]
+    min_relays: Option<usize>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct BridgeReadyWaitResponse {
+    relay_count: usize,
+    connected_relay_count: usize,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.ready.wait");
+    m.register_async_method("bridge.ready.wait", |params, ctx, extensions| async move {
+        require_bridge_auth(&extensions)?;
+        let params: BridgeReadyWaitParams = params
+            .parse()
+            .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+        if params.timeout_secs > READY_WAIT_MAX_TIMEOUT_SECS {
+            return Err(RpcError::InvalidParams(format!(
+                "timeout_secs must be at most {READY_WAIT_MAX_TIMEOUT_SECS}"
+            )));
+        }
+        let min_relays = params.min_relays.unwrap_or(1).max(1);
+        let (relay_count, connected_relay_count) = wait_for_connected_relays(
+            || ctx.relay_counts(),
+            min_relays,
+            Duration::from_secs(params.timeout_secs),
+            READY_WAIT_POLL_INTERVAL,
+        )
+        .await
+        .map_err(|(configured, connected)| RpcError::NoRelays {
+            configured,
+            connected,
+        })?;
+        Ok::<BridgeReadyWaitResponse, RpcError>(BridgeReadyWaitResponse {
+            relay_count,
+            connected_relay_count,
+        })
+    })?;
+    Ok(())
+}
+
+async fn wait_for_connected_relays<F, Fut>(
+    mut relay_counts: F,
+    min_relays: usize,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<(usize, usize), (usize, usize)>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = (usize, usize)>,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let counts = relay_counts().await;
+        if counts.1 >= min_relays {
+            return Ok(counts);
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Err(counts);
+        }
+        tokio::time::sleep_until(deadline.min(tokio::time::Instant::now() + poll_interval)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::wait_for_connected_relays;
+
+    #[tokio::test]
+    async fn returns_immediately_when_already_ready() {
+        let counts = wait_for_connected_relays(
+            || async { (2, 2) },
+            1,
+            Duration::ZERO,
+            Duration::from_millis(5),
+        )
+        .await;
+        assert_eq!(counts, Ok((2, 2)));
+    }
+
+    #[tokio::test]
+    async fn returns_once_relay_connects() {
+        let connected = Arc::new(AtomicUsize::new(0));
+        let connector = tokio::spawn({
+            let connected = connected.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                connected.store(1, Ordering::SeqCst);
+            }
+        });
+
+        let counts = wait_for_connected_relays(
+            || {
+                let connected = connected.clone();
+                async move { (1, connected.load(Ordering::SeqCst)) }
+            },
+            1,
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+        )
+        .await;
+
+        assert_eq!(counts, Ok((1, 1)));
+        connector.await.expect("connector task");
+    }
+
+    #[tokio::test]
+    async fn times_out_below_min_relays() {
+        let counts = wait_for_connected_relays(
+            || async { (3, 1) },
+            2,
+            Duration::from_millis(20),
+            Duration::from_millis(5),
+        )
+        .await;
+        assert_eq!(counts, Err((3, 1)));
+    }
+}
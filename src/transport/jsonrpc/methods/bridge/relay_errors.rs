@@ -0,0 +1,30 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use serde::Serialize;
+
+use crate::core::bridge::relay_errors::BridgeRelayError;
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+#[derive(Clone, Debug, Serialize)]
+struct BridgeRelayErrorsResponse {
+    capacity: usize,
+    relays: BTreeMap<String, Vec<BridgeRelayError>>,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.relay.errors");
+    m.register_async_method(
+        "bridge.relay.errors",
+        |_params, ctx, extensions| async move {
+            require_bridge_auth(&extensions)?;
+            Ok::<BridgeRelayErrorsResponse, RpcError>(BridgeRelayErrorsResponse {
+                capacity: ctx.state.bridge_config.relay_error_history,
+                relays: ctx.state.bridge_relay_errors.snapshot(),
+            })
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use serde::{Deserialize, Serialize};
+
+use crate::core::bridge::relay_stats::BridgeRelayStatsSnapshot;
+use crate::transport::jsonrpc::auth::require_bridge_auth;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+#[derive(Debug, Default, Deserialize)]
+struct BridgeRelayStatsParams {
+    #[serde(default)]
+    reset: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct BridgeRelayStatsResponse {
+    reset: bool,
+    #[serde(flatten)]
+    stats: BridgeRelayStatsSnapshot,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("bridge.relay.stats");
+    m.register_async_method("bridge.relay.stats", |params, ctx, extensions| async move {
+        require_bridge_auth(&extensions)?;
+        let params: BridgeRelayStatsParams = if params.is_object() {
+            params
+                .parse()
+                .map_err(|e| RpcError::InvalidParams(e.to_string()))?
+        } else {
+            BridgeRelayStatsParams::default()
+        };
+        let stats = if params.reset {
+            ctx.state.bridge_relay_stats.take()
+        } else {
+            ctx.state.bridge_relay_stats.snapshot()
+        };
+        Ok::<BridgeRelayStatsResponse, RpcError>(BridgeRelayStatsResponse {
+            reset: params.reset,
+            stats,
+        })
+    })?;
+    Ok(())
+}
@@ -1,22 +1,66 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
 use anyhow::Result;
-use nostr::Event;
-use radroots_nostr::prelude::RadrootsNostrEventBuilder;
+use nostr::{Event, Tag};
+use radroots_nostr::prelude::{
+    RadrootsNostrEventBuilder, RadrootsNostrEventId, RadrootsNostrFilter, RadrootsNostrKind,
+    RadrootsNostrTimestamp, radroots_nostr_parse_pubkey,
+};
 use radroots_nostr_signer::prelude::RadrootsNostrSignerBackend;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use crate::core::bridge::publish::BridgeRelayPublishResult;
+use crate::app::config::BridgeConfig;
+use crate::core::bridge::coordinate::BridgeCoordinate;
+use crate::core::bridge::delegation::{
+    DelegationConditions, delegation_tag, validate_delegated_event,
+};
+use crate::core::bridge::kinds::{aliased_kinds, canonical_kind, kind_label};
+use crate::core::bridge::publish::{
+    BridgePublishSettings, BridgeRelayPublishResult, failed_prepublish_execution,
+};
 use crate::core::bridge::store::{
     BridgeJobRecord, BridgeJobReservation, BridgeJobStatus, BridgeJobStoreError,
 };
 use crate::core::nip46::session::{Nip46SessionAuthority, Nip46SessionRole};
+use crate::transport::jsonrpc::methods::bridge::publish_report::{
+    BridgePublishReportEntry, verify_event_propagation,
+};
 use crate::transport::jsonrpc::nip46::{client as nip46_client, session as nip46_session};
 use crate::transport::jsonrpc::{RpcContext, RpcError};
 
 #[derive(Clone, Debug, Serialize)]
 pub(super) struct BridgePublishResponse {
     pub deduplicated: bool,
-    pub job: BridgeJobView,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job: Option<BridgeJobView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub propagation: Option<BridgePublishReportEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<Event>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub(super) struct BridgePublishOptions {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub ensure_newer_created_at: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub verify_propagation: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub preview: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegation: Option<BridgeDelegation>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(super) struct BridgeDelegation {
+    pub delegator: String,
+    pub conditions: String,
+    pub token: String,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -83,6 +127,32 @@ impl From<BridgeJobRecord> for BridgeJobView {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub(super) struct BridgeLabeledJobView {
+    #[serde(flatten)]
+    pub job: BridgeJobView,
+    pub event_kind_label: Option<&'static str>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub(super) enum BridgeJobOutput {
+    Plain(BridgeJobView),
+    Labeled(BridgeLabeledJobView),
+}
+
+impl BridgeJobView {
+    pub fn into_output(self, with_kind_label: bool) -> BridgeJobOutput {
+        if !with_kind_label {
+            return BridgeJobOutput::Plain(self);
+        }
+        BridgeJobOutput::Labeled(BridgeLabeledJobView {
+            event_kind_label: kind_label(self.event_kind),
+            job: self,
+        })
+    }
+}
+
 fn split_signer_usage(value: &str) -> (String, Option<String>) {
     match value.split_once(':') {
         Some(("nip46_session", session_id)) if !session_id.trim().is_empty() => {
@@ -153,9 +223,16 @@ pub(super) async fn resolve_bridge_signer(
                 session,
             })
         }
-        None => Ok(BridgeSignerSelection::EmbeddedServiceIdentity {
-            signer_pubkey_hex: bridge_signer_pubkey_hex(ctx)?,
-        }),
+        None => {
+            if !ctx.state.bridge_config.allows_self_sign_kind(event_kind) {
+                return Err(RpcError::Unauthorized(format!(
+                    "embedded service identity is not allowed to sign kind {event_kind}"
+                )));
+            }
+            Ok(BridgeSignerSelection::EmbeddedServiceIdentity {
+                signer_pubkey_hex: bridge_signer_pubkey_hex(ctx)?,
+            })
+        }
     }
 }
 
@@ -245,7 +322,7 @@ pub(super) async fn sign_bridge_event_builder(
     builder: RadrootsNostrEventBuilder,
     label: &str,
 ) -> Result<Event, RpcError> {
-    match signer {
+    let event = match signer {
         BridgeSignerSelection::EmbeddedServiceIdentity { .. } => ctx
             .state
             .bridge_signer
@@ -261,7 +338,298 @@ pub(super) async fn sign_bridge_event_builder(
                 nip46_client::sign_event(session, unsigned, label).await
             }
         },
+    }?;
+    ensure_bridge_tag_limit(&ctx.state.bridge_config, &event)?;
+    validate_delegated_event(&event).map_err(RpcError::InvalidParams)?;
+    Ok(event)
+}
+
+pub(super) async fn preview_bridge_event(
+    ctx: &RpcContext,
+    signer: &BridgeSignerSelection,
+    builder: RadrootsNostrEventBuilder,
+    label: &str,
+) -> Result<BridgePublishResponse, RpcError> {
+    let event = sign_bridge_event_builder(ctx, signer, builder, label).await?;
+    Ok(BridgePublishResponse {
+        deduplicated: false,
+        job: None,
+        propagation: None,
+        preview: Some(event),
+    })
+}
+
+pub(super) fn ensure_bridge_tag_limit(
+    config: &BridgeConfig,
+    event: &Event,
+) -> Result<(), RpcError> {
+    let tag_count = event.tags.len();
+    if tag_count > config.max_tags {
+        return Err(RpcError::InvalidParams(format!(
+            "event has {tag_count} tags, exceeding max_tags {}",
+            config.max_tags
+        )));
     }
+    Ok(())
+}
+
+pub(super) async fn ensure_publish_relays(
+    ctx: &RpcContext,
+    job: &BridgeJobRecord,
+    settings: &BridgePublishSettings,
+) -> Result<(), RpcError> {
+    let required = ctx.state.bridge_config.min_relays_for_publish;
+    if required == 0 || ctx.relay_counts().await.1 >= required {
+        return Ok(());
+    }
+    ctx.state.client.connect().await;
+    ctx.state
+        .client
+        .wait_for_connection(Duration::from_secs(
+            ctx.state.bridge_config.connect_timeout_secs,
+        ))
+        .await;
+    let (_, connected) = ctx.relay_counts().await;
+    if let Err(error) = check_publish_relays(required, connected) {
+        let _ = ctx.state.bridge_jobs.complete(
+            &job.job_id,
+            None,
+            failed_prepublish_execution(settings, error.to_string()),
+        );
+        return Err(error);
+    }
+    Ok(())
+}
+
+fn check_publish_relays(required: usize, connected: usize) -> Result<(), RpcError> {
+    if connected < required {
+        return Err(RpcError::InsufficientRelays {
+            required,
+            connected,
+        });
+    }
+    Ok(())
+}
+
+pub(super) async fn apply_bridge_publish_options(
+    ctx: &RpcContext,
+    options: &BridgePublishOptions,
+    builder: RadrootsNostrEventBuilder,
+    coordinate: Option<&BridgeCoordinate>,
+) -> Result<RadrootsNostrEventBuilder, RpcError> {
+    let builder = apply_bridge_expiration(options, builder, RadrootsNostrTimestamp::now())?;
+    let builder = apply_bridge_delegation(options, builder)?;
+    if !options.ensure_newer_created_at {
+        return Ok(builder);
+    }
+    let Some(coordinate) = coordinate else {
+        return Err(RpcError::InvalidParams(
+            "ensure_newer_created_at requires an addressable event".to_string(),
+        ));
+    };
+    ctx.require_relays().await?;
+    let BridgeCoordinate {
+        kind,
+        author,
+        d_tag,
+    } = coordinate;
+    let author_pubkey = radroots_nostr_parse_pubkey(author)
+        .map_err(|error| RpcError::Other(format!("invalid signer pubkey: {error}")))?;
+    let mut filter = RadrootsNostrFilter::new()
+        .kind(RadrootsNostrKind::from(*kind as u16))
+        .author(author_pubkey);
+    if !d_tag.is_empty() {
+        filter = filter.identifier(d_tag);
+    }
+    let prior = fetch_bridge_events(
+        ctx,
+        filter,
+        Duration::from_secs(ctx.state.bridge_config.connect_timeout_secs),
+    )
+    .await
+    .map_err(|error| {
+        RpcError::Other(format!(
+            "failed to fetch prior version of {kind}:{author}:{d_tag}: {error}"
+        ))
+    })?
+    .into_iter()
+    .map(|event| event.created_at)
+    .max();
+    Ok(builder.custom_created_at(created_at_after(RadrootsNostrTimestamp::now(), prior)))
+}
+
+pub(super) async fn verify_bridge_propagation(
+    ctx: &RpcContext,
+    options: &BridgePublishOptions,
+    job: &BridgeJobRecord,
+) -> Option<BridgePublishReportEntry> {
+    if !options.verify_propagation || job.status != BridgeJobStatus::Published {
+        return None;
+    }
+    let event_id = RadrootsNostrEventId::from_hex(job.event_id.as_deref()?).ok()?;
+    Some(verify_event_propagation(ctx, event_id).await)
+}
+
+pub(super) async fn fetch_bridge_events(
+    ctx: &RpcContext,
+    filter: RadrootsNostrFilter,
+    timeout: Duration,
+) -> Result<Vec<Event>, String> {
+    let max_event_bytes = ctx.state.bridge_config.max_event_bytes;
+    let timeout = ctx.fetch_timeout(timeout);
+    let aliases = &ctx.state.bridge_config.kind_aliases;
+    let requested = requested_kinds(&filter);
+    let filter = with_kind_aliases(filter, aliases);
+    let primary = ctx
+        .state
+        .client
+        .fetch_events(filter.clone(), timeout)
+        .await
+        .map_err(|error| error.to_string())?;
+    let primary = ctx
+        .state
+        .bridge_relay_stats
+        .retain_admitted(primary, max_event_bytes);
+    let primary = retain_requested_kinds(primary, &requested, aliases);
+    Ok(with_fallback(primary, || async {
+        if ctx.state.bridge_config.fallback_relays.is_empty() {
+            return Vec::new();
+        }
+        match ctx
+            .state
+            .bridge_fallback_client
+            .fetch_events(filter, timeout)
+            .await
+        {
+            Ok(events) => retain_requested_kinds(
+                ctx.state
+                    .bridge_relay_stats
+                    .retain_admitted(events, max_event_bytes),
+                &requested,
+                aliases,
+            ),
+            Err(error) => {
+                tracing::debug!("fallback relay fetch failed: {error}");
+                Vec::new()
+            }
+        }
+    })
+    .await)
+}
+
+fn with_kind_aliases(
+    filter: RadrootsNostrFilter,
+    aliases: &HashMap<u32, u32>,
+) -> RadrootsNostrFilter {
+    let Some(kinds) = filter.kinds.as_ref() else {
+        return filter;
+    };
+    let legacy = kinds
+        .iter()
+        .flat_map(|kind| aliased_kinds(u32::from(kind.as_u16()), aliases))
+        .filter_map(|kind| u16::try_from(kind).ok())
+        .map(RadrootsNostrKind::from)
+        .collect::<Vec<_>>();
+    filter.kinds(legacy)
+}
+
+fn requested_kinds(filter: &RadrootsNostrFilter) -> Vec<u32> {
+    filter
+        .kinds
+        .iter()
+        .flatten()
+        .map(|kind| u32::from(kind.as_u16()))
+        .collect()
+}
+
+fn retain_requested_kinds(
+    mut events: Vec<Event>,
+    requested: &[u32],
+    aliases: &HashMap<u32, u32>,
+) -> Vec<Event> {
+    if requested.is_empty() {
+        return events;
+    }
+    events.retain(|event| {
+        let kind = u32::from(event.kind.as_u16());
+        requested.contains(&kind) || requested.contains(&canonical_kind(kind, aliases))
+    });
+    events
+}
+
+pub(super) async fn with_fallback<T, F, Fut>(primary: Vec<T>, fallback: F) -> Vec<T>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Vec<T>>,
+{
+    if !primary.is_empty() {
+        return primary;
+    }
+    fallback().await
+}
+
+pub(super) fn apply_bridge_expiration(
+    options: &BridgePublishOptions,
+    builder: RadrootsNostrEventBuilder,
+    now: RadrootsNostrTimestamp,
+) -> Result<RadrootsNostrEventBuilder, RpcError> {
+    let Some(expiration) = options.expiration else {
+        return Ok(builder);
+    };
+    if expiration <= now.as_u64() {
+        return Err(RpcError::InvalidParams(format!(
+            "expiration {expiration} must be in the future"
+        )));
+    }
+    Ok(builder.tag(Tag::expiration(RadrootsNostrTimestamp::from(expiration))))
+}
+
+fn apply_bridge_delegation(
+    options: &BridgePublishOptions,
+    builder: RadrootsNostrEventBuilder,
+) -> Result<RadrootsNostrEventBuilder, RpcError> {
+    let Some(delegation) = options.delegation.as_ref() else {
+        return Ok(builder);
+    };
+    let delegator = radroots_nostr_parse_pubkey(&delegation.delegator).map_err(|error| {
+        RpcError::InvalidParams(format!("delegation.delegator is invalid: {error}"))
+    })?;
+    DelegationConditions::parse(&delegation.conditions).map_err(RpcError::InvalidParams)?;
+    let tag = delegation_tag(&delegator, &delegation.conditions, &delegation.token)
+        .map_err(RpcError::InvalidParams)?;
+    Ok(builder.tag(tag))
+}
+
+pub(super) fn created_at_after(
+    now: RadrootsNostrTimestamp,
+    prior: Option<RadrootsNostrTimestamp>,
+) -> RadrootsNostrTimestamp {
+    match prior {
+        Some(prior) if prior >= now => RadrootsNostrTimestamp::from(prior.as_u64() + 1),
+        _ => now,
+    }
+}
+
+pub(super) fn sanitize_bridge_content(
+    config: &BridgeConfig,
+    content: String,
+) -> Result<String, RpcError> {
+    let content = if config.trim_content {
+        content.trim().to_string()
+    } else {
+        content
+    };
+    if config.reject_control_chars
+        && let Some((index, ch)) = content
+            .char_indices()
+            .find(|(_, ch)| ch.is_control() && !matches!(ch, '\n' | '\r' | '\t'))
+    {
+        return Err(RpcError::InvalidParams(format!(
+            "content contains control character U+{:04X} at byte {index}",
+            ch as u32
+        )));
+    }
+    Ok(content)
 }
 
 pub(super) fn normalize_idempotency_key(value: Option<String>) -> Result<Option<String>, RpcError> {
@@ -317,20 +685,29 @@ pub(super) fn reserve_bridge_job(
 #[cfg(test)]
 mod tests {
     use radroots_identity::RadrootsIdentity;
-    use radroots_nostr::prelude::{RadrootsNostrClient, RadrootsNostrKeys, RadrootsNostrMetadata};
+    use radroots_nostr::prelude::{
+        RadrootsNostrClient, RadrootsNostrEventBuilder, RadrootsNostrFilter, RadrootsNostrKeys,
+        RadrootsNostrKind, RadrootsNostrMetadata, RadrootsNostrTimestamp,
+    };
 
     use crate::app::config::{BridgeConfig, BridgeDeliveryPolicy, Nip46Config};
     use crate::core::Radrootsd;
+    use crate::core::bridge::coordinate::BridgeCoordinate;
     use crate::core::bridge::store::{
         BRIDGE_PENDING_RECOVERY_SUMMARY, BridgeJobStatus, new_listing_publish_job,
     };
     use crate::core::nip46::session::{Nip46Session, Nip46SessionAuthority};
-    use crate::transport::jsonrpc::{MethodRegistry, RpcContext};
+    use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 
     use super::{
-        BridgeJobView, fingerprint_bridge_request, normalize_idempotency_key,
-        resolve_actor_bridge_signer, resolve_bridge_signer,
+        BridgeJobView, BridgePublishOptions, apply_bridge_expiration, apply_bridge_publish_options,
+        check_publish_relays, created_at_after, ensure_bridge_tag_limit,
+        fingerprint_bridge_request, normalize_idempotency_key, resolve_actor_bridge_signer,
+        resolve_bridge_signer, retain_requested_kinds, sanitize_bridge_content, with_fallback,
+        with_kind_aliases,
     };
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::Instant;
 
     #[test]
@@ -339,6 +716,241 @@ mod tests {
         assert!(err.to_string().contains("idempotency_key"));
     }
 
+    #[test]
+    fn with_kind_aliases_queries_legacy_kinds_for_current_kind() {
+        let aliases = HashMap::from([(30_400, 30_402), (30_401, 30_402), (30_010, 30_340)]);
+        let filter = RadrootsNostrFilter::new().kind(RadrootsNostrKind::from(30_402));
+
+        let filter = with_kind_aliases(filter, &aliases);
+
+        let kinds = filter
+            .kinds
+            .expect("kinds")
+            .into_iter()
+            .map(|kind| kind.as_u16())
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, vec![30_400, 30_401, 30_402]);
+    }
+
+    #[test]
+    fn retain_requested_kinds_decodes_legacy_kinds_as_current_kind() {
+        let keys = RadrootsNostrKeys::generate();
+        let aliases = HashMap::from([(30_400, 30_402)]);
+        let events = [30_400, 30_402, 30_340]
+            .into_iter()
+            .map(|kind| {
+                RadrootsNostrEventBuilder::new(RadrootsNostrKind::from(kind), "listing")
+                    .sign_with_keys(&keys)
+                    .expect("event")
+            })
+            .collect::<Vec<_>>();
+
+        let decoded = retain_requested_kinds(events.clone(), &[30_402], &aliases);
+        assert_eq!(
+            decoded
+                .iter()
+                .map(|event| event.kind.as_u16())
+                .collect::<Vec<_>>(),
+            vec![30_400, 30_402]
+        );
+        assert_eq!(
+            retain_requested_kinds(events.clone(), &[30_400], &aliases).len(),
+            1
+        );
+        assert_eq!(retain_requested_kinds(events, &[], &aliases).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn ensure_newer_created_at_requires_relays() {
+        let identity = RadrootsIdentity::generate();
+        let author = identity.public_key().to_hex();
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let state = Radrootsd::new(
+            identity,
+            metadata,
+            BridgeConfig::default(),
+            Nip46Config::default(),
+        )
+        .expect("state");
+        let ctx = RpcContext::new(state, MethodRegistry::default());
+        let options = BridgePublishOptions {
+            ensure_newer_created_at: true,
+            ..BridgePublishOptions::default()
+        };
+
+        let err = apply_bridge_publish_options(
+            &ctx,
+            &options,
+            RadrootsNostrEventBuilder::text_note("listing"),
+            Some(&BridgeCoordinate::new(30_402, author, "listing-1")),
+        )
+        .await
+        .expect_err("no relays");
+
+        assert!(matches!(err, RpcError::NoRelays { .. }));
+    }
+
+    #[test]
+    fn check_publish_relays_rejects_below_threshold() {
+        let err = check_publish_relays(2, 1).expect_err("below threshold");
+        assert!(matches!(
+            err,
+            RpcError::InsufficientRelays {
+                required: 2,
+                connected: 1
+            }
+        ));
+        assert!(check_publish_relays(2, 2).is_ok());
+    }
+
+    #[test]
+    fn check_publish_relays_requires_one_connected_relay_when_set_to_one() {
+        let err = check_publish_relays(1, 0).expect_err("no connected relays");
+        assert!(matches!(
+            err,
+            RpcError::InsufficientRelays {
+                required: 1,
+                connected: 0
+            }
+        ));
+        assert!(check_publish_relays(1, 1).is_ok());
+    }
+
+    #[tokio::test]
+    async fn fallback_is_queried_only_on_empty_primary_result() {
+        let fallback_calls = AtomicUsize::new(0);
+        let fallback = || async {
+            fallback_calls.fetch_add(1, Ordering::SeqCst);
+            vec!["fallback"]
+        };
+
+        assert_eq!(
+            with_fallback(vec!["primary"], fallback).await,
+            vec!["primary"]
+        );
+        assert_eq!(fallback_calls.load(Ordering::SeqCst), 0);
+
+        assert_eq!(with_fallback(Vec::new(), fallback).await, vec!["fallback"]);
+        assert_eq!(fallback_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn ensure_bridge_tag_limit_rejects_event_just_over_limit() {
+        let config = BridgeConfig {
+            max_tags: 3,
+            ..BridgeConfig::default()
+        };
+        let keys = RadrootsNostrKeys::generate();
+        let event_with_tags = |count: usize| {
+            RadrootsNostrEventBuilder::text_note("eggs")
+                .tags((0..count).map(|index| nostr::Tag::hashtag(format!("tag{index}"))))
+                .sign_with_keys(&keys)
+                .expect("event")
+        };
+
+        ensure_bridge_tag_limit(&config, &event_with_tags(3)).expect("at limit");
+        let err = ensure_bridge_tag_limit(&config, &event_with_tags(4)).expect_err("over limit");
+        assert!(matches!(err, RpcError::InvalidParams(_)));
+        assert!(err.to_string().contains("4 tags"));
+    }
+
+    #[test]
+    fn sanitize_bridge_content_is_passthrough_by_default() {
+        let content = sanitize_bridge_content(&BridgeConfig::default(), "  hi\u{7}  ".to_string())
+            .expect("content");
+        assert_eq!(content, "  hi\u{7}  ");
+    }
+
+    #[test]
+    fn sanitize_bridge_content_trims_when_enabled() {
+        let config = BridgeConfig {
+            trim_content: true,
+            ..BridgeConfig::default()
+        };
+        let content =
+            sanitize_bridge_content(&config, "\n  fresh eggs \t".to_string()).expect("content");
+        assert_eq!(content, "fresh eggs");
+    }
+
+    #[test]
+    fn sanitize_bridge_content_rejects_control_chars_when_enabled() {
+        let config = BridgeConfig {
+            reject_control_chars: true,
+            ..BridgeConfig::default()
+        };
+        let content = sanitize_bridge_content(&config, "line one\nline\ttwo\r\n".to_string())
+            .expect("content");
+        assert_eq!(content, "line one\nline\ttwo\r\n");
+        let err =
+            sanitize_bridge_content(&config, "bad\u{0}byte".to_string()).expect_err("control char");
+        assert!(matches!(err, RpcError::InvalidParams(_)));
+        assert!(err.to_string().contains("U+0000 at byte 3"));
+    }
+
+    #[test]
+    fn apply_bridge_expiration_appends_future_expiration_tag() {
+        let keys = RadrootsNostrKeys::generate();
+        let options = BridgePublishOptions {
+            expiration: Some(2_000),
+            ..BridgePublishOptions::default()
+        };
+        let unsigned = apply_bridge_expiration(
+            &options,
+            RadrootsNostrEventBuilder::text_note("pop-up market"),
+            RadrootsNostrTimestamp::from(1_000),
+        )
+        .expect("future expiration")
+        .build(keys.public_key());
+        assert!(
+            unsigned
+                .tags
+                .iter()
+                .any(|tag| tag.as_slice() == ["expiration".to_string(), "2000".to_string()])
+        );
+    }
+
+    #[test]
+    fn apply_bridge_expiration_rejects_past_values() {
+        let options = BridgePublishOptions {
+            expiration: Some(1_000),
+            ..BridgePublishOptions::default()
+        };
+        let err = apply_bridge_expiration(
+            &options,
+            RadrootsNostrEventBuilder::text_note("pop-up market"),
+            RadrootsNostrTimestamp::from(1_000),
+        )
+        .expect_err("past expiration");
+        assert!(matches!(err, RpcError::InvalidParams(_)));
+        assert!(err.to_string().contains("must be in the future"));
+    }
+
+    #[test]
+    fn created_at_after_bumps_past_newer_prior_version() {
+        let now = RadrootsNostrTimestamp::from(1_700_000_000);
+        let prior = RadrootsNostrTimestamp::from(1_700_000_100);
+        let keys = RadrootsNostrKeys::generate();
+
+        let event = RadrootsNostrEventBuilder::new(RadrootsNostrKind::from(30402), "listing")
+            .custom_created_at(created_at_after(now, Some(prior)))
+            .sign_with_keys(&keys)
+            .expect("signed event");
+
+        assert!(event.created_at > prior);
+        assert_eq!(event.created_at.as_u64(), 1_700_000_101);
+    }
+
+    #[test]
+    fn created_at_after_keeps_now_when_prior_is_older_or_missing() {
+        let now = RadrootsNostrTimestamp::from(1_700_000_000);
+        assert_eq!(
+            created_at_after(now, Some(RadrootsNostrTimestamp::from(1_699_999_999))),
+            now
+        );
+        assert_eq!(created_at_after(now, None), now);
+    }
+
     #[tokio::test]
     async fn resolve_bridge_signer_prefers_requested_nip46_session() {
         let identity = RadrootsIdentity::generate();
@@ -367,6 +979,7 @@ mod tests {
                 url: None,
                 image: None,
                 expires_at: Some(Instant::now() + std::time::Duration::from_secs(60)),
+                last_used_at: Instant::now(),
                 auth_required: false,
                 authorized: true,
                 auth_url: None,
@@ -386,6 +999,35 @@ mod tests {
         assert_eq!(signer.signer_mode(), "nip46_session:session-1");
     }
 
+    #[tokio::test]
+    async fn resolve_bridge_signer_enforces_self_sign_kinds() {
+        let identity = RadrootsIdentity::generate();
+        let metadata: RadrootsNostrMetadata =
+            serde_json::from_str(r#"{"name":"radrootsd-test"}"#).expect("metadata");
+        let state = Radrootsd::new(
+            identity.clone(),
+            metadata,
+            BridgeConfig {
+                self_sign_kinds: Some(vec![3422]),
+                ..BridgeConfig::default()
+            },
+            Nip46Config::default(),
+        )
+        .expect("state");
+        let ctx = RpcContext::new(state, MethodRegistry::default());
+
+        let signer = resolve_bridge_signer(&ctx, None, 3422)
+            .await
+            .expect("allowed kind");
+        assert_eq!(signer.signer_pubkey_hex(), identity.public_key_hex());
+
+        let err = match resolve_bridge_signer(&ctx, None, 1).await {
+            Ok(_) => panic!("expected disallowed kind to fail"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("not allowed to sign kind 1"));
+    }
+
     #[tokio::test]
     async fn resolve_actor_bridge_signer_rejects_missing_session_id() {
         let identity = RadrootsIdentity::generate();
@@ -438,6 +1080,7 @@ mod tests {
                 url: None,
                 image: None,
                 expires_at: Some(Instant::now() + std::time::Duration::from_secs(60)),
+                last_used_at: Instant::now(),
                 auth_required: false,
                 authorized: true,
                 auth_url: None,
@@ -491,6 +1134,7 @@ mod tests {
                 url: None,
                 image: None,
                 expires_at: Some(Instant::now() + std::time::Duration::from_secs(60)),
+                last_used_at: Instant::now(),
                 auth_required: false,
                 authorized: true,
                 auth_url: None,
@@ -561,6 +1205,7 @@ mod tests {
             url: None,
             image: None,
             expires_at: None,
+            last_used_at: Instant::now(),
             auth_required: false,
             authorized: true,
             auth_url: None,
@@ -628,4 +1273,34 @@ mod tests {
         assert_eq!(view.signer_mode, "nip46_session");
         assert_eq!(view.signer_session_id.as_deref(), Some("session-1"));
     }
+
+    #[test]
+    fn bridge_job_view_labels_kind_only_when_requested() {
+        let job = new_listing_publish_job(
+            "job-1".to_string(),
+            None,
+            "embedded_service_identity".to_string(),
+            30402,
+            None,
+            "30402:author:listing".to_string(),
+            BridgeDeliveryPolicy::Any,
+            None,
+        );
+        let plain = serde_json::to_value(BridgeJobView::from(job.clone()).into_output(false))
+            .expect("json");
+        assert!(plain.get("event_kind_label").is_none());
+        assert_eq!(plain["event_kind"], 30402);
+
+        let labeled =
+            serde_json::to_value(BridgeJobView::from(job.clone()).into_output(true)).expect("json");
+        assert_eq!(labeled["event_kind_label"], "listing");
+        assert_eq!(labeled["job_id"], "job-1");
+
+        let mut unknown = job;
+        unknown.event_kind = 65_000;
+        let labeled =
+            serde_json::to_value(BridgeJobView::from(unknown).into_output(true)).expect("json");
+        assert!(labeled["event_kind_label"].is_null());
+        assert!(labeled.get("event_kind_label").is_some());
+    }
 }
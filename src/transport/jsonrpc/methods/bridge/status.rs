@@ -21,6 +21,7 @@ struct BridgeStatusResponse {
     supported_signer_modes: Vec<String>,
     available_nip46_signer_sessions: usize,
     relay_count: usize,
+    connected_relay_count: usize,
     delivery_policy: BridgeDeliveryPolicy,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     delivery_quorum: Option<usize>,
@@ -41,7 +42,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
     registry.track("bridge.status");
     m.register_async_method("bridge.status", |_params, ctx, extensions| async move {
         require_bridge_auth(&extensions)?;
-        let relay_count = ctx.state.client.relays().await.len();
+        let (relay_count, connected_relay_count) = ctx.relay_counts().await;
         let snapshot = ctx.state.bridge_jobs.snapshot();
         let available_nip46_signer_sessions = ctx
             .state
@@ -63,6 +64,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
             ],
             available_nip46_signer_sessions,
             relay_count,
+            connected_relay_count,
             delivery_policy: ctx.state.bridge_config.delivery_policy,
             delivery_quorum: ctx.state.bridge_config.delivery_quorum,
             publish_max_attempts: ctx.state.bridge_config.publish_max_attempts,
@@ -58,6 +58,7 @@ mod tests {
         register_all(&mut root, ctx, registry).expect("register");
 
         assert!(root.method("bridge.status").is_some());
+        assert!(root.method("bridge.config").is_some());
         assert!(root.method("bridge.job.list").is_some());
         assert!(root.method("bridge.job.status").is_some());
         assert!(root.method("bridge.profile.publish").is_some());
@@ -77,6 +78,16 @@ mod tests {
         assert!(root.method("bridge.order.cancel").is_some());
         assert!(root.method("bridge.order.fulfillment.update").is_some());
         assert!(root.method("bridge.order.receipt").is_some());
+        assert!(root.method("bridge.order.validate_transition").is_some());
+        assert!(root.method("bridge.publish.report").is_some());
+        assert!(root.method("bridge.event.id").is_some());
+        assert!(root.method("bridge.event.publish_raw").is_some());
+        assert!(root.method("bridge.event.rebroadcast").is_some());
+        assert!(root.method("bridge.event.resolve").is_some());
+        assert!(root.method("bridge.ready.wait").is_some());
+        assert!(root.method("bridge.relay.stats").is_some());
+        assert!(root.method("bridge.relay.errors").is_some());
+        assert!(root.method("bridge.kinds").is_some());
         assert!(root.method("nip46.connect").is_none());
     }
 
@@ -137,6 +148,7 @@ mod tests {
                 .get()
                 .contains("\"available_nip46_signer_sessions\":0")
         );
+        assert!(response.get().contains("\"connected_relay_count\":0"));
         assert!(response.get().contains("\"accepted_jobs\":0"));
         assert!(response.get().contains("\"published_jobs\":0"));
         assert!(response.get().contains("\"failed_jobs\":0"));
@@ -158,4 +170,24 @@ mod tests {
             .expect("request");
         assert!(response.get().contains("\"result\":[]"));
     }
+
+    #[tokio::test]
+    async fn bridge_order_validate_transition_reports_reason() {
+        let registry = MethodRegistry::default();
+        let ctx = RpcContext::new(state(true, false), registry.clone());
+        let mut root = RpcModule::new(ctx.clone());
+        root.extensions_mut()
+            .insert(BridgeAuthorization::Authorized);
+        register_all(&mut root, ctx, registry).expect("register");
+
+        let (response, _stream) = root
+            .raw_json_request(
+                r#"{"jsonrpc":"2.0","method":"bridge.order.validate_transition","params":{"history":["request"],"next":"receipt"},"id":1}"#,
+                1,
+            )
+            .await
+            .expect("request");
+        assert!(response.get().contains("\"valid\":false"));
+        assert!(response.get().contains("fulfillment_update"));
+    }
 }
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use jsonrpsee::server::RpcModule;
@@ -160,6 +160,7 @@ async fn connect_bunker(
         url: info.url.clone(),
         image: info.image.clone(),
         expires_at,
+        last_used_at: Instant::now(),
         auth_required: false,
         authorized: true,
         auth_url: None,
@@ -239,6 +240,7 @@ async fn connect_nostrconnect(
         url: info.url.clone(),
         image: info.image.clone(),
         expires_at,
+        last_used_at: Instant::now(),
         auth_required: false,
         authorized: true,
         auth_url: None,
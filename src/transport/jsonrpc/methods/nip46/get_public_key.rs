@@ -23,7 +23,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
         let Nip46GetPublicKeyParams { session_id } = params
             .parse()
             .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-        let session = session::get_session(ctx.as_ref(), &session_id).await?;
+        let session = session::use_session(ctx.as_ref(), &session_id).await?;
         let (pubkey, updated) = request_get_public_key(&session).await?;
         if updated {
             if !ctx
@@ -13,6 +13,7 @@ pub mod ping;
 pub mod session_authorize;
 pub mod session_close;
 pub mod session_list;
+pub mod session_perms;
 pub mod session_require_auth;
 pub mod session_status;
 pub mod sign_event;
@@ -28,6 +29,7 @@ pub fn module(ctx: RpcContext, registry: MethodRegistry) -> Result<RpcModule<Rpc
     nip44::register(&mut m, &registry)?;
     sign_event::register(&mut m, &registry)?;
     session_status::register(&mut m, &registry)?;
+    session_perms::register(&mut m, &registry)?;
     session_close::register(&mut m, &registry)?;
     session_authorize::register(&mut m, &registry)?;
     session_require_auth::register(&mut m, &registry)?;
@@ -40,7 +40,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
         } = params
             .parse()
             .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-        let session = session::get_session(ctx.as_ref(), &session_id).await?;
+        let session = session::use_session(ctx.as_ref(), &session_id).await?;
         session::require_permission(&session, "nip04_encrypt")?;
         let public_key = radroots_nostr::prelude::radroots_nostr_parse_pubkey(&public_key)
             .map_err(|e| RpcError::InvalidParams(format!("invalid public_key: {e}")))?;
@@ -79,7 +79,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
         } = params
             .parse()
             .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-        let session = session::get_session(ctx.as_ref(), &session_id).await?;
+        let session = session::use_session(ctx.as_ref(), &session_id).await?;
         session::require_permission(&session, "nip04_decrypt")?;
         let public_key = radroots_nostr::prelude::radroots_nostr_parse_pubkey(&public_key)
             .map_err(|e| RpcError::InvalidParams(format!("invalid public_key: {e}")))?;
@@ -23,7 +23,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
         let Nip46PingParams { session_id } = params
             .parse()
             .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-        let session = session::get_session(ctx.as_ref(), &session_id).await?;
+        let session = session::use_session(ctx.as_ref(), &session_id).await?;
         Ok::<Nip46PingResponse, RpcError>(Nip46PingResponse {
             result: request_ping(&session).await?,
         })
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use jsonrpsee::server::RpcModule;
+use serde::{Deserialize, Serialize};
+
+use crate::core::nip46::session::{Nip46SessionCapabilities, session_capabilities};
+use crate::transport::jsonrpc::nip46::session;
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
+
+#[derive(Debug, Deserialize)]
+struct Nip46SessionPermsParams {
+    session_id: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct Nip46SessionPermsResponse {
+    session_id: String,
+    perms: Vec<String>,
+    #[serde(flatten)]
+    capabilities: Nip46SessionCapabilities,
+}
+
+pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
+    registry.track("nip46.session.perms");
+    m.register_async_method("nip46.session.perms", |params, ctx, _| async move {
+        let Nip46SessionPermsParams { session_id } = params
+            .parse()
+            .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
+        let session = session::get_session(ctx.as_ref(), &session_id).await?;
+        Ok::<Nip46SessionPermsResponse, RpcError>(Nip46SessionPermsResponse {
+            capabilities: session_capabilities(&session.perms),
+            session_id: session.id,
+            perms: session.perms,
+        })
+    })?;
+    Ok(())
+}
@@ -23,7 +23,7 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
         let Nip46SignEventParams { session_id, event } = params
             .parse()
             .map_err(|e| RpcError::InvalidParams(e.to_string()))?;
-        let session = session::get_session(ctx.as_ref(), &session_id).await?;
+        let session = session::use_session(ctx.as_ref(), &session_id).await?;
         session::require_sign_event_permission(&session, u32::from(event.kind.as_u16()))?;
         if event.pubkey != session.remote_signer_pubkey {
             return Err(RpcError::InvalidParams(
@@ -8,6 +8,8 @@ use crate::transport::jsonrpc::{MethodRegistry, RpcContext, RpcError};
 struct Nip46StatusResponse {
     ready: bool,
     session_ttl_secs: u64,
+    session_idle_timeout_secs: Option<u64>,
+    listener_rate_limited_events: u64,
 }
 
 pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Result<()> {
@@ -16,6 +18,8 @@ pub fn register(m: &mut RpcModule<RpcContext>, registry: &MethodRegistry) -> Res
         Ok::<Nip46StatusResponse, RpcError>(Nip46StatusResponse {
             ready: true,
             session_ttl_secs: ctx.state.nip46_config.session_ttl_secs,
+            session_idle_timeout_secs: ctx.state.nip46_config.session_idle_timeout_secs,
+            listener_rate_limited_events: ctx.state.nip46_author_limiter.dropped(),
         })
     })?;
     Ok(())
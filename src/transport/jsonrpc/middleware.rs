@@ -0,0 +1,741 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use anyhow::bail;
+use jsonrpsee::server::middleware::rpc::{
+    Batch, BatchEntry, BatchEntryErr, Notification, RpcServiceT,
+};
+use jsonrpsee::server::{BatchResponseBuilder, MethodResponse, ResponsePayload};
+use jsonrpsee::types::error::METHOD_NOT_FOUND_CODE;
+use jsonrpsee::types::{ErrorObjectOwned, Id, Request};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
+use tracing::Instrument;
+
+use crate::app::config::{MethodConcurrencyPolicy, RpcConfig};
+use crate::transport::jsonrpc::{MethodRegistry, RpcError};
+
+#[derive(Debug, Clone, Default)]
+pub struct InFlightRequests {
+    inner: Arc<InFlightInner>,
+}
+
+#[derive(Debug, Default)]
+struct InFlightInner {
+    count: AtomicUsize,
+    idle: Notify,
+}
+
+pub struct InFlightGuard {
+    inner: Arc<InFlightInner>,
+}
+
+impl InFlightRequests {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enter(&self) -> InFlightGuard {
+        self.inner.count.fetch_add(1, Ordering::SeqCst);
+        InFlightGuard {
+            inner: self.inner.clone(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.count.load(Ordering::SeqCst)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub async fn wait_idle(&self, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, async {
+            loop {
+                let idle = self.inner.idle.notified();
+                if self.is_empty() {
+                    return;
+                }
+                idle.await;
+            }
+        })
+        .await
+        .is_ok()
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.inner.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TrackInFlight<S> {
+    service: S,
+    in_flight: InFlightRequests,
+}
+
+impl<S> TrackInFlight<S> {
+    pub fn new(service: S, in_flight: InFlightRequests) -> Self {
+        Self { service, in_flight }
+    }
+}
+
+impl<S> RpcServiceT for TrackInFlight<S>
+where
+    S: RpcServiceT + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(
+        &self,
+        request: Request<'a>,
+    ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let guard = self.in_flight.enter();
+        let service = self.service.clone();
+        async move {
+            let response = service.call(request).await;
+            drop(guard);
+            response
+        }
+    }
+
+    fn batch<'a>(&self, batch: Batch<'a>) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        let guard = self.in_flight.enter();
+        let service = self.service.clone();
+        async move {
+            let response = service.batch(batch).await;
+            drop(guard);
+            response
+        }
+    }
+
+    fn notification<'a>(
+        &self,
+        notification: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        let guard = self.in_flight.enter();
+        let service = self.service.clone();
+        async move {
+            let response = service.notification(notification).await;
+            drop(guard);
+            response
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MethodConcurrency {
+    limits: Arc<HashMap<String, Arc<Semaphore>>>,
+    policy: MethodConcurrencyPolicy,
+}
+
+impl MethodConcurrency {
+    pub fn from_config(rpc_cfg: &RpcConfig, registry: &MethodRegistry) -> anyhow::Result<Self> {
+        let known = registry.list();
+        let mut limits = HashMap::with_capacity(rpc_cfg.method_concurrency.len());
+        for (method, limit) in &rpc_cfg.method_concurrency {
+            if !known.contains(method) {
+                bail!("rpc method_concurrency references unknown method {method}");
+            }
+            if *limit == 0 {
+                bail!("rpc method_concurrency for {method} must be greater than zero");
+            }
+            limits.insert(method.clone(), Arc::new(Semaphore::new(*limit)));
+        }
+        Ok(Self {
+            limits: Arc::new(limits),
+            policy: rpc_cfg.method_concurrency_policy,
+        })
+    }
+
+    pub async fn acquire(&self, method: &str) -> Result<Option<OwnedSemaphorePermit>, RpcError> {
+        match self.policy {
+            MethodConcurrencyPolicy::Reject => self.try_acquire(method),
+            MethodConcurrencyPolicy::Queue => {
+                let Some(semaphore) = self.limits.get(method) else {
+                    return Ok(None);
+                };
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map(Some)
+                    .map_err(|_| RpcError::ConcurrencyLimit(method.to_string()))
+            }
+        }
+    }
+
+    // Batched calls never queue: a batch holding permits for the same method
+    // could otherwise wait on itself.
+    pub fn try_acquire(&self, method: &str) -> Result<Option<OwnedSemaphorePermit>, RpcError> {
+        let Some(semaphore) = self.limits.get(method) else {
+            return Ok(None);
+        };
+        semaphore
+            .clone()
+            .try_acquire_owned()
+            .map(Some)
+            .map_err(|_| RpcError::ConcurrencyLimit(method.to_string()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LimitMethodConcurrency<S> {
+    service: S,
+    limits: MethodConcurrency,
+}
+
+impl<S> LimitMethodConcurrency<S> {
+    pub fn new(service: S, limits: MethodConcurrency) -> Self {
+        Self { service, limits }
+    }
+}
+
+impl<S> RpcServiceT for LimitMethodConcurrency<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse> + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(
+        &self,
+        request: Request<'a>,
+    ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let limits = self.limits.clone();
+        let service = self.service.clone();
+        async move {
+            let permit = match limits.acquire(request.method_name()).await {
+                Ok(permit) => permit,
+                Err(error) => {
+                    return MethodResponse::error(request.id(), ErrorObjectOwned::from(error));
+                }
+            };
+            let response = service.call(request).await;
+            drop(permit);
+            response
+        }
+    }
+
+    fn batch<'a>(
+        &self,
+        mut batch: Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        let limits = self.limits.clone();
+        let service = self.service.clone();
+        async move {
+            let mut permits = Vec::new();
+            for entry in batch.iter_mut() {
+                let Ok(BatchEntry::Call(request)) = entry else {
+                    continue;
+                };
+                match limits.try_acquire(request.method_name()) {
+                    Ok(permit) => permits.extend(permit),
+                    Err(error) => {
+                        let id = request.id();
+                        *entry = Err(BatchEntryErr::new(id, ErrorObjectOwned::from(error)));
+                    }
+                }
+            }
+            let response = service.batch(batch).await;
+            drop(permits);
+            response
+        }
+    }
+
+    fn notification<'a>(
+        &self,
+        notification: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.service.notification(notification)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EchoClientRequestId<S> {
+    service: S,
+    max_response_size: usize,
+}
+
+impl<S> EchoClientRequestId<S> {
+    pub fn new(service: S, max_response_size: u32) -> Self {
+        Self {
+            service,
+            max_response_size: max_response_size as usize,
+        }
+    }
+}
+
+impl<S> RpcServiceT for EchoClientRequestId<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse, BatchResponse = MethodResponse>
+        + Send
+        + Sync
+        + Clone
+        + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(
+        &self,
+        request: Request<'a>,
+    ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let service = self.service.clone();
+        let max_response_size = self.max_response_size;
+        async move {
+            let Some(client_request_id) = client_request_id(&request) else {
+                return service.call(request).await;
+            };
+            let id = request.id().into_owned();
+            let span = tracing::info_span!(
+                "rpc_call",
+                method = %request.method_name(),
+                client_request_id = %client_request_id
+            );
+            let response = service.call(request).instrument(span).await;
+            if response.is_subscription() {
+                return response;
+            }
+            attach_client_request_id(response, id, &client_request_id, max_response_size)
+        }
+    }
+
+    fn batch<'a>(&self, batch: Batch<'a>) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        let echo = self.clone();
+        async move {
+            let mut responses = BatchResponseBuilder::new_with_limit(echo.max_response_size);
+            let mut got_notification = false;
+            for entry in batch.into_iter() {
+                let response = match entry {
+                    Ok(BatchEntry::Call(request)) => echo.call(request).await,
+                    Ok(BatchEntry::Notification(notification)) => {
+                        got_notification = true;
+                        let _ = echo.service.notification(notification).await;
+                        continue;
+                    }
+                    Err(error) => {
+                        let (error, id) = error.into_parts();
+                        MethodResponse::error(id, error)
+                    }
+                };
+                if let Err(response) = responses.append(response) {
+                    return response;
+                }
+            }
+            if responses.is_empty() && got_notification {
+                MethodResponse::notification()
+            } else {
+                MethodResponse::from_batch(responses.finish())
+            }
+        }
+    }
+
+    fn notification<'a>(
+        &self,
+        notification: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.service.notification(notification)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SuggestUnknownMethod<S> {
+    service: S,
+    registry: MethodRegistry,
+    enabled: bool,
+}
+
+impl<S> SuggestUnknownMethod<S> {
+    pub fn new(service: S, registry: MethodRegistry, enabled: bool) -> Self {
+        Self {
+            service,
+            registry,
+            enabled,
+        }
+    }
+}
+
+impl<S> RpcServiceT for SuggestUnknownMethod<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse> + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(
+        &self,
+        request: Request<'a>,
+    ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let service = self.service.clone();
+        let registry = self.registry.clone();
+        let enabled = self.enabled;
+        async move {
+            let method = request.method_name().to_string();
+            let id = request.id().into_owned();
+            let response = service.call(request).await;
+            if !enabled || response.as_error_code() != Some(METHOD_NOT_FOUND_CODE) {
+                return response;
+            }
+            let extensions = response.extensions().clone();
+            let did_you_mean = closest_method(&method, &registry.list());
+            MethodResponse::error(
+                id,
+                ErrorObjectOwned::from(RpcError::MethodNotFound {
+                    method,
+                    did_you_mean,
+                }),
+            )
+            .with_extensions(extensions)
+        }
+    }
+
+    fn batch<'a>(&self, batch: Batch<'a>) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        self.service.batch(batch)
+    }
+
+    fn notification<'a>(
+        &self,
+        notification: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.service.notification(notification)
+    }
+}
+
+fn closest_method(method: &str, known: &[String]) -> Option<String> {
+    let max_distance = (method.chars().count() / 3).clamp(1, 4);
+    known
+        .iter()
+        .map(|candidate| (edit_distance(method, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by(|left, right| left.0.cmp(&right.0).then_with(|| left.1.cmp(right.1)))
+        .map(|(_, candidate)| candidate.clone())
+}
+
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut previous = (0..=right.len()).collect::<Vec<_>>();
+    for (i, left_char) in left.chars().enumerate() {
+        let mut current = vec![i + 1; right.len() + 1];
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != *right_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[right.len()]
+}
+
+#[derive(Deserialize)]
+struct ClientRequestIdParams {
+    client_request_id: Option<String>,
+}
+
+fn client_request_id(request: &Request<'_>) -> Option<String> {
+    let params = request.params()?;
+    serde_json::from_str::<ClientRequestIdParams>(params.get())
+        .ok()?
+        .client_request_id
+        .filter(|value| !value.trim().is_empty())
+}
+
+fn attach_client_request_id(
+    response: MethodResponse,
+    id: Id<'static>,
+    client_request_id: &str,
+    max_response_size: usize,
+) -> MethodResponse {
+    let Ok(Value::Object(mut envelope)) = serde_json::from_str(response.as_json().get()) else {
+        return response;
+    };
+    let extensions = response.extensions().clone();
+    let echoed = if let Some(result) = envelope.remove("result") {
+        MethodResponse::response(
+            id,
+            ResponsePayload::success(with_client_request_id(result, "result", client_request_id)),
+            max_response_size,
+        )
+    } else if let Some(Value::Object(mut error)) = envelope.remove("error") {
+        let code = error
+            .get("code")
+            .and_then(Value::as_i64)
+            .unwrap_or_default() as i32;
+        let message = error
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let data = with_client_request_id(
+            error
+                .remove("data")
+                .unwrap_or_else(|| Value::Object(Map::new())),
+            "data",
+            client_request_id,
+        );
+        MethodResponse::error(id, ErrorObjectOwned::owned(code, message, Some(data)))
+    } else {
+        return response;
+    };
+    echoed.with_extensions(extensions)
+}
+
+fn with_client_request_id(value: Value, field: &str, client_request_id: &str) -> Value {
+    let mut object = match value {
+        Value::Object(object) => object,
+        value => Map::from_iter([(field.to_string(), value)]),
+    };
+    object.insert(
+        "client_request_id".to_string(),
+        Value::String(client_request_id.to_string()),
+    );
+    Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::app::config::{MethodConcurrencyPolicy, RpcConfig};
+    use crate::transport::jsonrpc::{MethodRegistry, RpcError};
+
+    use jsonrpsee::server::{MethodResponse, ResponsePayload};
+    use jsonrpsee::types::{ErrorObjectOwned, Id};
+    use serde_json::{Value, json};
+
+    use super::{
+        InFlightRequests, MethodConcurrency, attach_client_request_id, closest_method,
+        edit_distance,
+    };
+
+    fn response_json(response: &MethodResponse) -> Value {
+        serde_json::from_str(response.as_json().get()).expect("response json")
+    }
+
+    fn method_concurrency(policy: MethodConcurrencyPolicy) -> MethodConcurrency {
+        let registry = MethodRegistry::default();
+        registry.track("bridge.listing.publish");
+        registry.track("bridge.status");
+        let rpc_cfg = RpcConfig {
+            method_concurrency: [("bridge.listing.publish".to_string(), 1)].into(),
+            method_concurrency_policy: policy,
+            ..RpcConfig::default()
+        };
+        MethodConcurrency::from_config(&rpc_cfg, &registry).expect("method concurrency")
+    }
+
+    #[tokio::test]
+    async fn wait_idle_returns_immediately_without_requests() {
+        let in_flight = InFlightRequests::new();
+        assert!(in_flight.wait_idle(Duration::from_millis(10)).await);
+    }
+
+    #[tokio::test]
+    async fn in_flight_request_completes_within_drain_window() {
+        let in_flight = InFlightRequests::new();
+        let guard = in_flight.enter();
+        let request = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(guard);
+        });
+
+        assert_eq!(in_flight.len(), 1);
+        assert!(in_flight.wait_idle(Duration::from_secs(1)).await);
+        assert!(in_flight.is_empty());
+        request.await.expect("request task");
+    }
+
+    #[tokio::test]
+    async fn drain_window_expires_while_request_is_stuck() {
+        let in_flight = InFlightRequests::new();
+        let _guard = in_flight.enter();
+        assert!(!in_flight.wait_idle(Duration::from_millis(20)).await);
+        assert_eq!(in_flight.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn over_limit_call_is_rejected_under_reject_policy() {
+        let limits = method_concurrency(MethodConcurrencyPolicy::Reject);
+        let held = limits
+            .acquire("bridge.listing.publish")
+            .await
+            .expect("first permit");
+        assert!(held.is_some());
+
+        let err = limits
+            .acquire("bridge.listing.publish")
+            .await
+            .expect_err("second call rejected");
+        assert!(matches!(err, RpcError::ConcurrencyLimit(_)));
+        assert!(
+            limits
+                .acquire("bridge.status")
+                .await
+                .expect("unlimited method")
+                .is_none()
+        );
+
+        drop(held);
+        assert!(
+            limits
+                .acquire("bridge.listing.publish")
+                .await
+                .expect("permit after release")
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn over_limit_call_waits_under_queue_policy() {
+        let limits = method_concurrency(MethodConcurrencyPolicy::Queue);
+        let held = limits
+            .acquire("bridge.listing.publish")
+            .await
+            .expect("first permit");
+
+        let queued = tokio::time::timeout(
+            Duration::from_millis(20),
+            limits.acquire("bridge.listing.publish"),
+        )
+        .await;
+        assert!(queued.is_err());
+
+        let waiter = tokio::spawn({
+            let limits = limits.clone();
+            async move { limits.acquire("bridge.listing.publish").await }
+        });
+        drop(held);
+        let permit = waiter.await.expect("waiter task").expect("queued permit");
+        assert!(permit.is_some());
+    }
+
+    #[test]
+    fn method_concurrency_rejects_unknown_methods_and_zero_limits() {
+        let registry = MethodRegistry::default();
+        registry.track("bridge.status");
+        let unknown = RpcConfig {
+            method_concurrency: [("bridge.unknown".to_string(), 1)].into(),
+            ..RpcConfig::default()
+        };
+        let err = MethodConcurrency::from_config(&unknown, &registry).expect_err("unknown method");
+        assert!(err.to_string().contains("unknown method bridge.unknown"));
+
+        let zero = RpcConfig {
+            method_concurrency: [("bridge.status".to_string(), 0)].into(),
+            ..RpcConfig::default()
+        };
+        assert!(MethodConcurrency::from_config(&zero, &registry).is_err());
+    }
+
+    #[test]
+    fn closest_method_suggests_registered_name_for_typo() {
+        let known = vec![
+            "bridge.job.list".to_string(),
+            "bridge.job.status".to_string(),
+            "bridge.status".to_string(),
+        ];
+
+        assert_eq!(edit_distance("bridge.stauts", "bridge.status"), 2);
+        assert_eq!(
+            closest_method("bridge.stauts", &known).as_deref(),
+            Some("bridge.status")
+        );
+        assert_eq!(
+            closest_method("bridge.job.lsit", &known).as_deref(),
+            Some("bridge.job.list")
+        );
+        assert_eq!(closest_method("nip46.connect", &known), None);
+    }
+
+    #[test]
+    fn method_not_found_error_carries_suggestion() {
+        let response = MethodResponse::error(
+            Id::Number(3),
+            ErrorObjectOwned::from(RpcError::MethodNotFound {
+                method: "bridge.stauts".to_string(),
+                did_you_mean: Some("bridge.status".to_string()),
+            }),
+        );
+
+        let json = response_json(&response);
+        assert_eq!(json["error"]["code"], -32601);
+        assert_eq!(json["error"]["data"]["did_you_mean"], "bridge.status");
+    }
+
+    #[test]
+    fn client_request_id_is_echoed_in_success_result() {
+        let response = MethodResponse::response(
+            Id::Number(7),
+            ResponsePayload::success(json!({ "relay_count": 2 })),
+            usize::MAX,
+        );
+
+        let echoed = attach_client_request_id(response, Id::Number(7), "trace-123", usize::MAX);
+
+        let json = response_json(&echoed);
+        assert_eq!(json["id"], 7);
+        assert_eq!(json["result"]["relay_count"], 2);
+        assert_eq!(json["result"]["client_request_id"], "trace-123");
+        assert!(echoed.is_success());
+    }
+
+    #[test]
+    fn client_request_id_is_echoed_in_error_data() {
+        let response = MethodResponse::error(
+            Id::Str("req-1".into()),
+            ErrorObjectOwned::from(RpcError::InvalidParams("missing event".to_string())),
+        );
+
+        let echoed =
+            attach_client_request_id(response, Id::Str("req-1".into()), "trace-456", usize::MAX);
+
+        let json = response_json(&echoed);
+        assert_eq!(json["id"], "req-1");
+        assert_eq!(json["error"]["data"]["client_request_id"], "trace-456");
+        assert!(echoed.is_error());
+    }
+
+    #[test]
+    fn client_request_id_is_merged_into_existing_error_data() {
+        let response = MethodResponse::error(
+            Id::Number(9),
+            ErrorObjectOwned::from(RpcError::NoRelays {
+                configured: 2,
+                connected: 0,
+            }),
+        );
+
+        let echoed = attach_client_request_id(response, Id::Number(9), "trace-789", usize::MAX);
+
+        let json = response_json(&echoed);
+        assert_eq!(json["error"]["code"], -32000);
+        assert_eq!(json["error"]["data"]["reason"], "disconnected");
+        assert_eq!(json["error"]["data"]["configured"], 2);
+        assert_eq!(json["error"]["data"]["client_request_id"], "trace-789");
+    }
+
+    #[test]
+    fn client_request_id_wraps_non_object_results() {
+        let response =
+            MethodResponse::response(Id::Number(4), ResponsePayload::success(true), usize::MAX);
+
+        let echoed = attach_client_request_id(response, Id::Number(4), "trace-321", usize::MAX);
+
+        let json = response_json(&echoed);
+        assert_eq!(json["result"]["result"], true);
+        assert_eq!(json["result"]["client_request_id"], "trace-321");
+    }
+}
@@ -10,7 +10,9 @@ use crate::core::Radrootsd;
 
 mod auth;
 mod context;
+mod deadline;
 mod error;
+mod middleware;
 mod params;
 mod registry;
 mod server;
@@ -20,12 +22,14 @@ pub mod nip46;
 
 pub use context::RpcContext;
 pub use error::RpcError;
+pub use middleware::InFlightRequests;
 pub use registry::MethodRegistry;
 
 pub async fn start_rpc(
     state: Radrootsd,
     addr: SocketAddr,
     rpc_cfg: &RpcConfig,
+    in_flight: InFlightRequests,
 ) -> Result<ServerHandle> {
     state.bridge_config.validate()?;
     let registry = MethodRegistry::default();
@@ -33,8 +37,18 @@ pub async fn start_rpc(
     let bridge_config = ctx.state.bridge_config.clone();
 
     let mut root = RpcModule::new(ctx.clone());
-    methods::register_all(&mut root, ctx, registry)?;
-
-    let handle = server::start_server(addr, rpc_cfg, &bridge_config, root).await?;
+    methods::register_all(&mut root, ctx, registry.clone())?;
+    let method_limits = middleware::MethodConcurrency::from_config(rpc_cfg, &registry)?;
+
+    let handle = server::start_server(
+        addr,
+        rpc_cfg,
+        &bridge_config,
+        root,
+        in_flight,
+        method_limits,
+        registry,
+    )
+    .await?;
     Ok(handle)
 }
@@ -9,6 +9,14 @@ pub async fn get_session(ctx: &RpcContext, session_id: &str) -> Result<Nip46Sess
         .ok_or_else(|| RpcError::InvalidParams("unknown session".to_string()))
 }
 
+pub async fn use_session(ctx: &RpcContext, session_id: &str) -> Result<Nip46Session, RpcError> {
+    ctx.state
+        .nip46_sessions
+        .touch(session_id)
+        .await
+        .ok_or_else(|| RpcError::InvalidParams("unknown session".to_string()))
+}
+
 pub fn require_permission(session: &Nip46Session, perm: &str) -> Result<(), RpcError> {
     if session.auth_required && !session.authorized {
         return Err(auth_required_error(session));
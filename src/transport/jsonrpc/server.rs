@@ -1,22 +1,31 @@
 #![forbid(unsafe_code)]
 
 use std::net::SocketAddr;
+use std::time::Instant;
 
 use anyhow::Result;
+use jsonrpsee::server::middleware::rpc::RpcServiceBuilder;
 use jsonrpsee::server::{
     BatchRequestConfig, HttpBody, HttpRequest, RpcModule, ServerBuilder, ServerConfigBuilder,
     ServerHandle,
 };
 
 use crate::app::config::{BridgeConfig, RpcConfig};
-use crate::transport::jsonrpc::RpcContext;
-use crate::transport::jsonrpc::auth;
+use crate::transport::jsonrpc::middleware::{
+    EchoClientRequestId, InFlightRequests, LimitMethodConcurrency, MethodConcurrency,
+    SuggestUnknownMethod, TrackInFlight,
+};
+use crate::transport::jsonrpc::{MethodRegistry, RpcContext};
+use crate::transport::jsonrpc::{auth, deadline};
 
 pub async fn start_server(
     addr: SocketAddr,
     rpc_cfg: &RpcConfig,
     bridge_cfg: &BridgeConfig,
     root: RpcModule<RpcContext>,
+    in_flight: InFlightRequests,
+    method_limits: MethodConcurrency,
+    registry: MethodRegistry,
 ) -> Result<ServerHandle> {
     let mut builder = ServerConfigBuilder::new()
         .max_request_body_size(rpc_cfg.max_request_body_size)
@@ -36,6 +45,8 @@ pub async fn start_server(
 
     let server_cfg = builder.build();
     let bridge_bearer_token = bridge_cfg.bearer_token().map(str::to_owned);
+    let max_response_body_size = rpc_cfg.max_response_body_size;
+    let suggest_unknown_methods = rpc_cfg.suggest_unknown_methods;
     let server = ServerBuilder::with_config(server_cfg)
         .set_http_middleware(tower::ServiceBuilder::new().map_request(
             move |mut request: HttpRequest<HttpBody>| {
@@ -47,9 +58,29 @@ pub async fn start_server(
                     bridge_bearer_token.as_deref(),
                 );
                 request.extensions_mut().insert(bridge_auth);
+                if let Some(request_deadline) = deadline::request_deadline(
+                    request
+                        .headers()
+                        .get(deadline::REQUEST_TIMEOUT_HEADER)
+                        .and_then(|value| value.to_str().ok()),
+                    Instant::now(),
+                ) {
+                    request.extensions_mut().insert(request_deadline);
+                }
                 request
             },
         ))
+        .set_rpc_middleware(
+            RpcServiceBuilder::new()
+                .layer_fn(move |service| TrackInFlight::new(service, in_flight.clone()))
+                .layer_fn(move |service| EchoClientRequestId::new(service, max_response_body_size))
+                .layer_fn(move |service| {
+                    SuggestUnknownMethod::new(service, registry.clone(), suggest_unknown_methods)
+                })
+                .layer_fn(move |service| {
+                    LimitMethodConcurrency::new(service, method_limits.clone())
+                }),
+        )
         .build(addr)
         .await?;
     Ok(server.start(root))
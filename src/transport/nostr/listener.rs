@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Result, anyhow};
 use nostr::JsonUtil;
@@ -8,20 +8,24 @@ use nostr::nips::nip46::{
     NostrConnectMessage, NostrConnectRequest, NostrConnectResponse, ResponseResult,
 };
 use tokio::sync::broadcast;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
+use crate::app::config::Nip46Config;
 use crate::core::nip46::session::{
     Nip46Session, PendingNostrRequest, session_expires_at, sign_event_allowed,
 };
+use crate::core::nip46::watermark::Nip46Watermark;
 use crate::core::state::Radrootsd;
 use radroots_nostr::prelude::{
-    RadrootsNostrEventBuilder, RadrootsNostrFilter, RadrootsNostrKind,
-    RadrootsNostrRelayPoolNotification, RadrootsNostrTimestamp, radroots_nostr_filter_tag,
+    RadrootsNostrClient, RadrootsNostrEventBuilder, RadrootsNostrFilter, RadrootsNostrKind,
+    RadrootsNostrRelayPoolNotification, RadrootsNostrRelayUrl, RadrootsNostrSubscriptionId,
+    RadrootsNostrTimestamp, radroots_nostr_filter_tag,
 };
 
 const DEFAULT_TIMEOUT_SECS: u64 = 10;
 
 pub fn spawn_nip46_listener(radrootsd: Radrootsd) {
+    spawn_nip46_watermark_flush(radrootsd.nip46_watermark.clone());
     tokio::spawn(async move {
         if let Err(error) = run_nip46_listener(radrootsd).await {
             warn!("NIP-46 listener stopped: {error}");
@@ -29,37 +33,89 @@ pub fn spawn_nip46_listener(radrootsd: Radrootsd) {
     });
 }
 
-async fn run_nip46_listener(radrootsd: Radrootsd) -> Result<()> {
+fn spawn_nip46_watermark_flush(watermark: Nip46Watermark) {
+    tokio::spawn(async move {
+        loop {
+            watermark.wait_for_flush().await;
+            if let Err(err) = watermark.flush().await {
+                warn!("NIP-46 watermark persist failed: {err}");
+            }
+        }
+    });
+}
+
+async fn run_nip46_listener(mut radrootsd: Radrootsd) -> Result<()> {
+    if let Some(relays) = dedicated_listener_relays(&radrootsd.nip46_config) {
+        // Signer traffic, including sessions created here, runs on its own connections.
+        let client = RadrootsNostrClient::new(radrootsd.keys.clone());
+        for relay in relays {
+            client.add_relay(relay).await?;
+        }
+        radrootsd.client = client;
+    }
     radrootsd.client.connect().await;
     radrootsd
         .client
         .wait_for_connection(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
         .await;
 
-    let filter = RadrootsNostrFilter::new()
-        .kind(RadrootsNostrKind::NostrConnect)
-        .since(RadrootsNostrTimestamp::now());
-    let filter = radroots_nostr_filter_tag(filter, "p", vec![radrootsd.pubkey.to_hex()])?;
+    let relays = listener_relays(&radrootsd.nip46_config);
+    let idle_window = listener_idle_window(&radrootsd.nip46_config);
     let mut notifications = radrootsd.client.notifications();
-    let subscription = radrootsd.client.subscribe(filter, None).await?;
-
-    info!("NIP-46 listener subscribed: {}", subscription.val);
+    let mut subscription_id = subscribe_nip46_requests(&radrootsd, relays.as_ref()).await?;
+    info!("NIP-46 listener subscribed: {subscription_id}");
+    let mut last_activity = Instant::now();
 
     loop {
-        let notification = match notifications.recv().await {
-            Ok(notification) => notification,
-            Err(broadcast::error::RecvError::Lagged(_)) => continue,
-            Err(broadcast::error::RecvError::Closed) => {
+        let wait = idle_window.map(|window| window.saturating_sub(last_activity.elapsed()));
+        let notification = match recv_or_idle(&mut notifications, wait).await {
+            Some(Ok(notification)) => notification,
+            Some(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+            Some(Err(broadcast::error::RecvError::Closed)) => {
                 return Err(anyhow!("nip46 listener notification closed"));
             }
+            None => {
+                info!("NIP-46 listener idle, resubscribing: {subscription_id}");
+                radrootsd.client.unsubscribe(&subscription_id).await;
+                subscription_id = subscribe_nip46_requests(&radrootsd, relays.as_ref()).await?;
+                last_activity = Instant::now();
+                continue;
+            }
         };
-        let RadrootsNostrRelayPoolNotification::Event { event, .. } = notification else {
+        let RadrootsNostrRelayPoolNotification::Event {
+            relay_url,
+            subscription_id: event_subscription_id,
+            event,
+        } = notification
+        else {
             continue;
         };
+        if event_subscription_id != subscription_id {
+            continue;
+        }
+        last_activity = Instant::now();
+        if let Some(relays) = relays.as_ref()
+            && !relays.iter().any(|relay| relay_matches(relay, &relay_url))
+        {
+            continue;
+        }
         let event = (*event).clone();
         if event.kind != RadrootsNostrKind::NostrConnect {
             continue;
         }
+        if !listener_accepts(&radrootsd.nip46_config, &event.pubkey.to_hex()) {
+            continue;
+        }
+        if !radrootsd.nip46_author_limiter.allow(&event.pubkey.to_hex()) {
+            debug!("NIP-46 listener rate limited author {}", event.pubkey);
+            continue;
+        }
+        if !radrootsd
+            .bridge_relay_stats
+            .admit_event(&event, radrootsd.bridge_config.max_event_bytes)
+        {
+            continue;
+        }
 
         let decrypted =
             match nip44::decrypt(radrootsd.keys.secret_key(), &event.pubkey, &event.content) {
@@ -81,6 +137,12 @@ async fn run_nip46_listener(radrootsd: Radrootsd) -> Result<()> {
         }
 
         let request_id = message.id().to_string();
+        if !radrootsd.nip46_watermark.record(
+            format!("{}:{request_id}", event.pubkey.to_hex()),
+            event.created_at.as_u64(),
+        ) {
+            continue;
+        }
         let request = match message.to_request() {
             Ok(value) => value,
             Err(err) => {
@@ -96,10 +158,87 @@ async fn run_nip46_listener(radrootsd: Radrootsd) -> Result<()> {
             response_message,
         )
         .map_err(|err| anyhow!("nip46 response build failed: {err}"))?;
-        let _ = radrootsd.client.send_event_builder(response_event).await;
+        let _ = match relays.as_ref() {
+            Some(relays) => {
+                radrootsd
+                    .client
+                    .send_event_builder_to(relays.clone(), response_event)
+                    .await
+            }
+            None => radrootsd.client.send_event_builder(response_event).await,
+        };
     }
 }
 
+async fn subscribe_nip46_requests(
+    radrootsd: &Radrootsd,
+    relays: Option<&Vec<String>>,
+) -> Result<RadrootsNostrSubscriptionId> {
+    let since = radrootsd
+        .nip46_watermark
+        .resubscribe_since(RadrootsNostrTimestamp::now().as_u64());
+    let filter = RadrootsNostrFilter::new()
+        .kind(RadrootsNostrKind::NostrConnect)
+        .since(RadrootsNostrTimestamp::from(since));
+    let filter = radroots_nostr_filter_tag(filter, "p", vec![radrootsd.pubkey.to_hex()])?;
+    let subscription = match relays {
+        Some(relays) => {
+            radrootsd
+                .client
+                .subscribe_to(relays.clone(), filter, None)
+                .await?
+        }
+        None => radrootsd.client.subscribe(filter, None).await?,
+    };
+    Ok(subscription.val)
+}
+
+fn listener_idle_window(config: &Nip46Config) -> Option<Duration> {
+    (config.listener_idle_resubscribe_secs > 0)
+        .then(|| Duration::from_secs(config.listener_idle_resubscribe_secs))
+}
+
+async fn recv_or_idle<T: Clone>(
+    notifications: &mut broadcast::Receiver<T>,
+    wait: Option<Duration>,
+) -> Option<Result<T, broadcast::error::RecvError>> {
+    match wait {
+        Some(wait) => tokio::time::timeout(wait, notifications.recv()).await.ok(),
+        None => Some(notifications.recv().await),
+    }
+}
+
+fn dedicated_listener_relays(config: &Nip46Config) -> Option<&[String]> {
+    (!config.listener_relays.is_empty()).then_some(config.listener_relays.as_slice())
+}
+
+fn listener_relays(config: &Nip46Config) -> Option<Vec<String>> {
+    if let Some(relays) = dedicated_listener_relays(config) {
+        return Some(relays.to_vec());
+    }
+    if config.relays.is_empty() {
+        None
+    } else {
+        Some(config.relays.clone())
+    }
+}
+
+fn listener_accepts(config: &Nip46Config, author: &str) -> bool {
+    let listed = |authors: &[String]| {
+        authors
+            .iter()
+            .any(|listed| listed.trim().eq_ignore_ascii_case(author))
+    };
+    if listed(&config.listener_banned_authors) {
+        return false;
+    }
+    config.listener_allowed_authors.is_empty() || listed(&config.listener_allowed_authors)
+}
+
+fn relay_matches(configured: &str, relay_url: &RadrootsNostrRelayUrl) -> bool {
+    RadrootsNostrRelayUrl::parse(configured).is_ok_and(|configured| &configured == relay_url)
+}
+
 pub(crate) async fn handle_request(
     radrootsd: &Radrootsd,
     client_pubkey: &radroots_nostr::prelude::RadrootsNostrPublicKey,
@@ -138,6 +277,7 @@ pub(crate) async fn handle_request(
                 url: None,
                 image: None,
                 expires_at,
+                last_used_at: Instant::now(),
                 auth_required: false,
                 authorized: true,
                 auth_url: None,
@@ -323,7 +463,7 @@ async fn session_for_client(
     client_pubkey: &radroots_nostr::prelude::RadrootsNostrPublicKey,
 ) -> Result<Nip46Session, NostrConnectResponse> {
     let session_id = client_pubkey.to_hex();
-    match radrootsd.nip46_sessions.get(&session_id).await {
+    match radrootsd.nip46_sessions.touch(&session_id).await {
         Some(session) => Ok(session),
         None => Err(NostrConnectResponse::with_error("unauthorized")),
     }
@@ -366,3 +506,112 @@ async fn auth_challenge(
         Some(auth_url),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use radroots_nostr::prelude::RadrootsNostrRelayUrl;
+    use tokio::sync::broadcast;
+
+    use super::{
+        dedicated_listener_relays, listener_accepts, listener_idle_window, listener_relays,
+        recv_or_idle, relay_matches,
+    };
+    use crate::app::config::Nip46Config;
+
+    #[test]
+    fn listener_idle_window_is_disabled_by_zero() {
+        assert_eq!(
+            listener_idle_window(&Nip46Config::default()),
+            Some(Duration::from_secs(300))
+        );
+        let config = Nip46Config {
+            listener_idle_resubscribe_secs: 0,
+            ..Nip46Config::default()
+        };
+        assert!(listener_idle_window(&config).is_none());
+    }
+
+    #[tokio::test]
+    async fn recv_or_idle_reports_idle_period() {
+        let (sender, mut notifications) = broadcast::channel::<u32>(4);
+
+        assert!(
+            recv_or_idle(&mut notifications, Some(Duration::from_millis(10)))
+                .await
+                .is_none()
+        );
+
+        sender.send(7).expect("send");
+        let received = recv_or_idle(&mut notifications, Some(Duration::from_millis(10)))
+            .await
+            .expect("not idle")
+            .expect("notification");
+        assert_eq!(received, 7);
+    }
+
+    #[test]
+    fn listener_uses_all_relays_when_subset_is_unset() {
+        assert!(listener_relays(&Nip46Config::default()).is_none());
+    }
+
+    #[test]
+    fn listener_uses_configured_relay_subset() {
+        let config = Nip46Config {
+            relays: vec!["wss://relay-a.example.com".to_string()],
+            ..Nip46Config::default()
+        };
+        let relays = listener_relays(&config).expect("relay subset");
+        assert_eq!(relays, vec!["wss://relay-a.example.com".to_string()]);
+
+        let relay_a = RadrootsNostrRelayUrl::parse("wss://relay-a.example.com").expect("relay-a");
+        let relay_b = RadrootsNostrRelayUrl::parse("wss://relay-b.example.com").expect("relay-b");
+        assert!(relays.iter().any(|relay| relay_matches(relay, &relay_a)));
+        assert!(!relays.iter().any(|relay| relay_matches(relay, &relay_b)));
+    }
+
+    #[test]
+    fn listener_subscribes_on_dedicated_relays_when_configured() {
+        assert!(dedicated_listener_relays(&Nip46Config::default()).is_none());
+
+        let config = Nip46Config {
+            relays: vec!["wss://relay-a.example.com".to_string()],
+            listener_relays: vec!["wss://signer.example.com".to_string()],
+            ..Nip46Config::default()
+        };
+        assert_eq!(
+            dedicated_listener_relays(&config),
+            Some(["wss://signer.example.com".to_string()].as_slice())
+        );
+        assert_eq!(
+            listener_relays(&config),
+            Some(vec!["wss://signer.example.com".to_string()])
+        );
+    }
+
+    const AUTHOR_A: &str = "1bd5d8e5e3bbd2e3b0bcbd0b1a8a4cd2e01a0b7a3c4de5f6a7b8c9d0e1f2a3b4";
+    const AUTHOR_B: &str = "9f3a6c2d1e0b8a7f6e5d4c3b2a1908f7e6d5c4b3a29180f7e6d5c4b3a2918070";
+
+    #[test]
+    fn listener_accepts_whitelisted_author_only() {
+        let config = Nip46Config {
+            listener_allowed_authors: vec![AUTHOR_A.to_uppercase()],
+            ..Nip46Config::default()
+        };
+        assert!(listener_accepts(&config, AUTHOR_A));
+        assert!(!listener_accepts(&config, AUTHOR_B));
+        assert!(listener_accepts(&Nip46Config::default(), AUTHOR_B));
+    }
+
+    #[test]
+    fn listener_ban_overrides_whitelist() {
+        let config = Nip46Config {
+            listener_allowed_authors: vec![AUTHOR_A.to_string(), AUTHOR_B.to_string()],
+            listener_banned_authors: vec![format!(" {} ", AUTHOR_A.to_uppercase())],
+            ..Nip46Config::default()
+        };
+        assert!(!listener_accepts(&config, AUTHOR_A));
+        assert!(listener_accepts(&config, AUTHOR_B));
+    }
+}
@@ -1 +1,3 @@
 pub mod listener;
+pub mod relay_errors;
+pub mod relay_trace;
@@ -0,0 +1,79 @@
+use nostr::RelayMessage;
+use radroots_nostr::prelude::{RadrootsNostrClient, RadrootsNostrRelayPoolNotification};
+use tokio::sync::broadcast;
+use tracing::info;
+
+use crate::core::bridge::relay_errors::BridgeRelayErrorLog;
+
+pub fn spawn_relay_error_log(client: RadrootsNostrClient, log: BridgeRelayErrorLog) {
+    tokio::spawn(async move {
+        let mut notifications = client.notifications();
+        loop {
+            let notification = match notifications.recv().await {
+                Ok(notification) => notification,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    info!("relay error log skipped {skipped} relay messages");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if let RadrootsNostrRelayPoolNotification::Message { relay_url, message } = notification
+                && let Some((kind, detail)) = relay_error(&message)
+            {
+                log.push(relay_url.as_str(), kind, detail);
+            }
+        }
+    });
+}
+
+fn relay_error(message: &RelayMessage<'_>) -> Option<(&'static str, String)> {
+    match message {
+        RelayMessage::Notice(message) => Some(("notice", message.to_string())),
+        RelayMessage::Closed {
+            subscription_id,
+            message,
+        } => Some(("closed", format!("{subscription_id}: {message}"))),
+        RelayMessage::Ok {
+            event_id,
+            status: false,
+            message,
+        } => Some(("rejected", format!("{event_id}: {message}"))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nostr::{RelayMessage, SubscriptionId};
+    use radroots_nostr::prelude::{RadrootsNostrEventBuilder, RadrootsNostrKeys};
+
+    use super::relay_error;
+
+    #[test]
+    fn relay_error_keeps_notices_closures_and_rejections() {
+        let event = RadrootsNostrEventBuilder::text_note("eggs")
+            .sign_with_keys(&RadrootsNostrKeys::generate())
+            .expect("event");
+
+        assert_eq!(
+            relay_error(&RelayMessage::notice("slow down")),
+            Some(("notice", "slow down".to_string()))
+        );
+        assert_eq!(
+            relay_error(&RelayMessage::closed(
+                SubscriptionId::new("sub-1"),
+                "auth-required: sign in"
+            )),
+            Some(("closed", "sub-1: auth-required: sign in".to_string()))
+        );
+        assert_eq!(
+            relay_error(&RelayMessage::ok(event.id, false, "blocked: spam")),
+            Some(("rejected", format!("{}: blocked: spam", event.id)))
+        );
+        assert_eq!(relay_error(&RelayMessage::ok(event.id, true, "")), None);
+        assert_eq!(
+            relay_error(&RelayMessage::eose(SubscriptionId::new("sub-1"))),
+            None
+        );
+    }
+}
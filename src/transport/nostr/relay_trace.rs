@@ -0,0 +1,104 @@
+use nostr::RelayMessage;
+use radroots_nostr::prelude::{RadrootsNostrClient, RadrootsNostrRelayPoolNotification};
+use tokio::sync::broadcast;
+use tracing::info;
+
+pub fn spawn_relay_trace(client: RadrootsNostrClient) {
+    tokio::spawn(async move {
+        let mut notifications = client.notifications();
+        loop {
+            let notification = match notifications.recv().await {
+                Ok(notification) => notification,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    info!(target: "radrootsd::relay_trace", "skipped {skipped} relay messages");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if let RadrootsNostrRelayPoolNotification::Message { relay_url, message } = notification
+            {
+                info!(
+                    target: "radrootsd::relay_trace",
+                    "{relay_url} <- {}",
+                    summarize_relay_message(&message)
+                );
+            }
+        }
+    });
+}
+
+fn summarize_relay_message(message: &RelayMessage<'_>) -> String {
+    match message {
+        RelayMessage::Event {
+            subscription_id,
+            event,
+        } => format!(
+            "EVENT {subscription_id} id={} kind={} content=<redacted {} bytes>",
+            event.id,
+            event.kind.as_u16(),
+            event.content.len()
+        ),
+        RelayMessage::Ok {
+            event_id,
+            status,
+            message,
+        } => format!("OK {event_id} {status} {message:?}"),
+        RelayMessage::EndOfStoredEvents(subscription_id) => format!("EOSE {subscription_id}"),
+        RelayMessage::Notice(message) => format!("NOTICE {message:?}"),
+        RelayMessage::Closed {
+            subscription_id,
+            message,
+        } => format!("CLOSED {subscription_id} {message:?}"),
+        RelayMessage::Auth { .. } => "AUTH <redacted challenge>".to_string(),
+        RelayMessage::Count {
+            subscription_id,
+            count,
+        } => format!("COUNT {subscription_id} {count}"),
+        _ => "OTHER".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nostr::{RelayMessage, SubscriptionId};
+    use radroots_nostr::prelude::{RadrootsNostrEventBuilder, RadrootsNostrKeys};
+
+    use super::summarize_relay_message;
+
+    #[test]
+    fn summarize_surfaces_ok_messages() {
+        let event = RadrootsNostrEventBuilder::text_note("eggs")
+            .sign_with_keys(&RadrootsNostrKeys::generate())
+            .expect("event");
+        let summary =
+            summarize_relay_message(&RelayMessage::ok(event.id, false, "blocked: rate limited"));
+        assert_eq!(
+            summary,
+            format!("OK {} false \"blocked: rate limited\"", event.id)
+        );
+    }
+
+    #[test]
+    fn summarize_redacts_event_content() {
+        let event = RadrootsNostrEventBuilder::text_note("secret pickup code 1234")
+            .sign_with_keys(&RadrootsNostrKeys::generate())
+            .expect("event");
+        let summary =
+            summarize_relay_message(&RelayMessage::event(SubscriptionId::new("sub-1"), event));
+        assert!(summary.starts_with("EVENT sub-1 id="));
+        assert!(summary.contains("<redacted 23 bytes>"));
+        assert!(!summary.contains("1234"));
+    }
+
+    #[test]
+    fn summarize_reports_eose_and_notice() {
+        assert_eq!(
+            summarize_relay_message(&RelayMessage::eose(SubscriptionId::new("sub-1"))),
+            "EOSE sub-1"
+        );
+        assert_eq!(
+            summarize_relay_message(&RelayMessage::notice("slow down")),
+            "NOTICE \"slow down\""
+        );
+    }
+}